@@ -0,0 +1,39 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use slugify_rs::{slugify, slugify_with_config, SlugConfig};
+
+const ASCII: &str = "The quick brown fox jumps over the lazy dog again";
+const CJK: &str = "影師嗎你好世界東京大阪京都札幌福岡那覇仙台";
+const MIXED: &str = "Crème brûlée, 影師 and Straße: déjà vu 🦀 at the Æbeltoft café \
+                     — naïve façade, Ελληνικά κείμενα, Привет мир!";
+
+fn bench_inputs(c: &mut Criterion) {
+    c.bench_function("ascii_50", |b| {
+        b.iter(|| slugify(black_box(ASCII), "", "-", None, false, 5))
+    });
+    c.bench_function("cjk_20", |b| {
+        b.iter(|| slugify(black_box(CJK), "", "-", None, false, 5))
+    });
+    c.bench_function("mixed_100", |b| {
+        b.iter(|| slugify(black_box(MIXED), "", "-", None, false, 5))
+    });
+}
+
+fn bench_options(c: &mut Criterion) {
+    let stop_words = "the,a,an,of,and,or,over,to,in,again";
+    c.bench_function("stop_words_10", |b| {
+        b.iter(|| slugify(black_box(ASCII), stop_words, "-", None, false, 5))
+    });
+    c.bench_function("max_length", |b| {
+        b.iter(|| slugify(black_box(ASCII), "", "-", Some(20), false, 5))
+    });
+    c.bench_function("randomness", |b| {
+        b.iter(|| slugify(black_box(ASCII), "", "-", None, true, 5))
+    });
+    let config = SlugConfig::default();
+    c.bench_function("with_config", |b| {
+        b.iter(|| slugify_with_config(black_box(ASCII), &config))
+    });
+}
+
+criterion_group!(benches, bench_inputs, bench_options);
+criterion_main!(benches);
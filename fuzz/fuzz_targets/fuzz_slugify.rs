@@ -0,0 +1,20 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use slugify_rs::{slugify_with_config, IntoStopWords, SlugConfig};
+
+fuzz_target!(|input: (&str, &str, &str, Option<u8>, Option<u8>)| {
+    let (data, stop_words, sep, max_length, input_max_length) = input;
+    let config = SlugConfig {
+        stop_words: stop_words.into_stop_words(),
+        separator: String::from(sep),
+        max_length: max_length.map(usize::from),
+        input_max_length: input_max_length.map(usize::from),
+        ..SlugConfig::default()
+    };
+    let slug = slugify_with_config(data, &config);
+
+    if let Some(max_length) = max_length {
+        assert!(slug.len() <= usize::from(max_length));
+    }
+});
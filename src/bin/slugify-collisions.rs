@@ -0,0 +1,31 @@
+//! Reads one title per line from stdin and reports the lines that would get the same slug.
+
+use std::io::{self, BufRead};
+
+use slugify_rs::{slugify_count_collisions, SlugConfig};
+
+fn main() -> io::Result<()> {
+    let lines = io::stdin()
+        .lock()
+        .lines()
+        .collect::<io::Result<Vec<String>>>()?;
+    let inputs: Vec<&str> = lines.iter().map(String::as_str).collect();
+
+    let mut collisions: Vec<(String, Vec<usize>)> =
+        slugify_count_collisions(&inputs, &SlugConfig::default())
+            .into_iter()
+            .filter(|(_, indices)| indices.len() > 1)
+            .collect();
+    collisions.sort_by_key(|(_, indices)| indices[0]);
+
+    for (slug, indices) in &collisions {
+        let lines: Vec<String> = indices.iter().map(|i| (i + 1).to_string()).collect();
+        println!("{:?}: lines {}", slug, lines.join(", "));
+    }
+    println!(
+        "{} colliding slugs across {} lines",
+        collisions.len(),
+        inputs.len()
+    );
+    Ok(())
+}
@@ -0,0 +1,149 @@
+use std::collections::HashMap;
+
+use crate::{slugify_with_config, SlugConfig, SlugError};
+
+/// Maps every slug generated from `inputs` with `config` to the indices of the inputs that
+/// produce it.
+///
+/// Entries holding more than one index are collisions.
+///
+/// ```rust
+/// # use slugify_rs::{slugify_count_collisions, SlugConfig};
+/// let slugs = slugify_count_collisions(&["Hello World", "Other", "hello, world!"], &SlugConfig::default());
+/// assert_eq!(slugs["hello-world"], [0, 2]);
+/// assert_eq!(slugs["other"], [1]);
+/// ```
+pub fn slugify_count_collisions(
+    inputs: &[&str],
+    config: &SlugConfig,
+) -> HashMap<String, Vec<usize>> {
+    let mut slugs: HashMap<String, Vec<usize>> = HashMap::new();
+    for (i, input) in inputs.iter().enumerate() {
+        slugs
+            .entry(slugify_with_config(input, config))
+            .or_default()
+            .push(i);
+    }
+    slugs
+}
+
+/// Generates slugs for `s` until `is_unique` accepts one, regenerating the random component up
+/// to `max_retries` times.
+///
+/// Fails with [`SlugError::NotUnique`] when every attempt is rejected. Without `randomness` every
+/// attempt gives the same slug, so only one is made.
+///
+/// ```rust
+/// # use slugify_rs::{slugify_unique, SlugConfig};
+/// let taken = vec![String::from("hello-world")];
+/// let config = SlugConfig { randomness: true, ..SlugConfig::default() };
+/// let slug = slugify_unique("Hello World", &config, 3, |slug| !taken.iter().any(|t| t == slug)).unwrap();
+/// assert!(slug.starts_with("hello-world-"));
+/// ```
+pub fn slugify_unique(
+    s: &str,
+    config: &SlugConfig,
+    max_retries: usize,
+    is_unique: impl Fn(&str) -> bool,
+) -> Result<String, SlugError> {
+    let attempts = if config.randomness {
+        max_retries + 1
+    } else {
+        1
+    };
+    let mut config = config.clone();
+    for _ in 0..attempts {
+        let slug = slugify_with_config(s, &config);
+        if is_unique(&slug) {
+            return Ok(slug);
+        }
+        // a fixed seed would give the same random component every time
+        if let Some(seed) = config.seed.as_mut() {
+            *seed = seed.wrapping_add(1);
+        }
+    }
+    Err(SlugError::NotUnique)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{slugify_count_collisions, slugify_unique, SlugConfig, SlugError};
+    use std::cell::Cell;
+
+    #[test]
+    fn test_count_collisions() {
+        let inputs = ["The Post", "the-post", "Post", "THE POST!", "", "???"];
+        let slugs = slugify_count_collisions(&inputs, &SlugConfig::default());
+        assert_eq!(slugs.len(), 3);
+        assert_eq!(slugs["the-post"], [0, 1, 3]);
+        assert_eq!(slugs["post"], [2]);
+        assert_eq!(slugs[""], [4, 5]);
+
+        let config = SlugConfig {
+            stop_words: vec![String::from("the")],
+            ..SlugConfig::default()
+        };
+        let slugs = slugify_count_collisions(&inputs, &config);
+        assert_eq!(slugs["post"], [0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_count_collisions_empty() {
+        assert!(slugify_count_collisions(&[], &SlugConfig::default()).is_empty());
+    }
+
+    #[test]
+    fn test_unique() {
+        let config = SlugConfig::default();
+        assert_eq!(
+            slugify_unique("Hello World", &config, 3, |_| true),
+            Ok(String::from("hello-world"))
+        );
+
+        // without randomness there is nothing to retry
+        let calls = Cell::new(0);
+        let result = slugify_unique("Hello World", &config, 3, |_| {
+            calls.set(calls.get() + 1);
+            false
+        });
+        assert_eq!(result, Err(SlugError::NotUnique));
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    fn test_unique_retries() {
+        let config = SlugConfig {
+            randomness: true,
+            ..SlugConfig::default()
+        };
+        let calls = Cell::new(0);
+        let slug = slugify_unique("Hello World", &config, 5, |_| {
+            calls.set(calls.get() + 1);
+            calls.get() == 3
+        })
+        .unwrap();
+        assert_eq!(calls.get(), 3);
+        assert!(slug.starts_with("hello-world-"));
+
+        calls.set(0);
+        let result = slugify_unique("Hello World", &config, 5, |_| {
+            calls.set(calls.get() + 1);
+            false
+        });
+        assert_eq!(result, Err(SlugError::NotUnique));
+        assert_eq!(calls.get(), 6);
+    }
+
+    #[cfg(feature = "deterministic")]
+    #[test]
+    fn test_unique_seeded() {
+        let config = SlugConfig {
+            randomness: true,
+            seed: Some(42),
+            ..SlugConfig::default()
+        };
+        let first = slugify_unique("Hello World", &config, 0, |_| true).unwrap();
+        let second = slugify_unique("Hello World", &config, 1, |slug| slug != first).unwrap();
+        assert_ne!(first, second);
+    }
+}
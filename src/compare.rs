@@ -0,0 +1,126 @@
+/// Splits a slug into its words, treating any run of non-alphanumeric characters as a separator.
+fn words(slug: &str) -> impl Iterator<Item = &str> {
+    slug.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+}
+
+/// Compares two slugs ignoring case and separator differences.
+///
+/// ```rust
+/// # use slugify_rs::slug_eq;
+/// assert!(slug_eq("hello-world", "HELLO_WORLD"));
+/// assert!(!slug_eq("hello-world", "hello-worlds"));
+/// ```
+pub fn slug_eq(a: &str, b: &str) -> bool {
+    words(a)
+        .map(str::to_lowercase)
+        .eq(words(b).map(str::to_lowercase))
+}
+
+/// Compares two slugs ignoring separator differences only.
+///
+/// ```rust
+/// # use slugify_rs::slug_eq_case_sensitive;
+/// assert!(slug_eq_case_sensitive("hello-world", "hello_world"));
+/// assert!(!slug_eq_case_sensitive("hello-world", "HELLO_WORLD"));
+/// ```
+pub fn slug_eq_case_sensitive(a: &str, b: &str) -> bool {
+    words(a).eq(words(b))
+}
+
+/// The words added, removed and kept between two versions of a slug, see [`slug_diff`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SlugDiff {
+    /// Words of the new slug missing from the old one, in the order of the new slug.
+    pub added: Vec<String>,
+    /// Words of the old slug missing from the new one, in the order of the old slug.
+    pub removed: Vec<String>,
+    /// Words found in both slugs, in the order of the old slug.
+    pub unchanged: Vec<String>,
+}
+
+/// Compares the words of two slugs split on `sep`, for example to update a search index after
+/// a title was edited. Each word is listed once, however often it appears.
+///
+/// ```rust
+/// # use slugify_rs::slug_diff;
+/// let diff = slug_diff("the-quick-brown-fox", "the-slow-brown-dog", "-");
+/// assert_eq!(diff.added, ["slow", "dog"]);
+/// assert_eq!(diff.removed, ["quick", "fox"]);
+/// assert_eq!(diff.unchanged, ["the", "brown"]);
+/// ```
+pub fn slug_diff(old: &str, new: &str, sep: &str) -> SlugDiff {
+    let old = distinct_words(old, sep);
+    let new = distinct_words(new, sep);
+
+    let (unchanged, removed): (Vec<String>, Vec<String>) =
+        old.into_iter().partition(|word| new.contains(word));
+    let added = new
+        .into_iter()
+        .filter(|word| !unchanged.contains(word))
+        .collect();
+    SlugDiff {
+        added,
+        removed,
+        unchanged,
+    }
+}
+
+/// Splits a slug on `sep` into its distinct words, in order of first appearance.
+fn distinct_words(slug: &str, sep: &str) -> Vec<String> {
+    let mut words: Vec<String> = Vec::new();
+    let mut push = |word: &str| {
+        if !word.is_empty() && !words.iter().any(|w| w == word) {
+            words.push(word.to_owned());
+        }
+    };
+    if sep.is_empty() {
+        push(slug);
+    } else {
+        slug.split(sep).for_each(push);
+    }
+    words
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{slug_diff, slug_eq, slug_eq_case_sensitive, SlugDiff};
+
+    #[test]
+    fn test_slug_eq() {
+        assert!(slug_eq("hello-world", "HELLO_WORLD"));
+        assert!(slug_eq("hello-world", "-hello--world-"));
+        assert!(slug_eq("hello.world", "Hello World"));
+        assert!(slug_eq("", "---"));
+        assert!(!slug_eq("hello-world", "helloworld"));
+        assert!(!slug_eq("hello-world", "world-hello"));
+    }
+
+    #[test]
+    fn test_slug_eq_case_sensitive() {
+        assert!(slug_eq_case_sensitive("hello-world", "hello_world"));
+        assert!(slug_eq_case_sensitive("Hello-World", "Hello.World"));
+        assert!(!slug_eq_case_sensitive("hello-world", "Hello-World"));
+    }
+
+    #[test]
+    fn test_slug_diff() {
+        let diff = slug_diff("rust-web-framework", "rust_async_web_framework", "_");
+        assert_eq!(diff.added, ["rust", "async", "web", "framework"]);
+        assert_eq!(diff.removed, ["rust-web-framework"]);
+        assert!(diff.unchanged.is_empty());
+
+        let diff = slug_diff("a-b-a-c", "c-b-b-d", "-");
+        assert_eq!(diff.added, ["d"]);
+        assert_eq!(diff.removed, ["a"]);
+        assert_eq!(diff.unchanged, ["b", "c"]);
+    }
+
+    #[test]
+    fn test_slug_diff_empty() {
+        assert_eq!(slug_diff("", "", "-"), SlugDiff::default());
+        assert_eq!(slug_diff("--", "-hello-", "-").added, ["hello"]);
+        assert_eq!(slug_diff("hello", "", "-").removed, ["hello"]);
+        assert_eq!(slug_diff("ab", "ab", "").unchanged, ["ab"]);
+    }
+}
@@ -0,0 +1,136 @@
+use std::cell::RefCell;
+
+use crate::SlugConfig;
+
+thread_local! {
+    static DEFAULT_CONFIG: RefCell<Option<SlugConfig>> = RefCell::new(None);
+}
+
+/// Sets the config the `slugify!` macro starts from on the current thread.
+///
+/// Options passed to the macro are still applied on top of it. The default is per thread, so
+/// other threads keep their own, but it applies to every `slugify!` call on this thread,
+/// including the ones made inside dependencies. Use [`with_default_config`] to limit it to a
+/// block of code.
+///
+/// ```rust
+/// # use slugify_rs::{clear_default_config, set_default_config, slugify, SlugConfig};
+/// set_default_config(SlugConfig {
+///     separator: String::from("_"),
+///     ..SlugConfig::default()
+/// });
+/// assert_eq!(slugify!("hello world"), "hello_world");
+/// assert_eq!(slugify!("hello world", separator = "."), "hello.world");
+///
+/// clear_default_config();
+/// assert_eq!(slugify!("hello world"), "hello-world");
+/// ```
+pub fn set_default_config(config: SlugConfig) {
+    DEFAULT_CONFIG.with(|default| *default.borrow_mut() = Some(config));
+}
+
+/// Removes the config set with [`set_default_config`] on the current thread.
+pub fn clear_default_config() {
+    DEFAULT_CONFIG.with(|default| *default.borrow_mut() = None);
+}
+
+/// Returns the default config of the current thread, or [`SlugConfig::default`] if none is set.
+pub fn default_config() -> SlugConfig {
+    DEFAULT_CONFIG.with(|default| {
+        default
+            .borrow()
+            .as_ref()
+            .map_or_else(SlugConfig::default, Clone::clone)
+    })
+}
+
+/// Runs `f` with `config` as the default config of the current thread, restoring the previous
+/// default afterwards, even if `f` panics.
+///
+/// ```rust
+/// # use slugify_rs::{slugify, with_default_config, SlugConfig};
+/// let config = SlugConfig {
+///     stop_words: vec![String::from("the")],
+///     ..SlugConfig::default()
+/// };
+/// let slug = with_default_config(config, || slugify!("the hello world"));
+/// assert_eq!(slug, "hello-world");
+/// assert_eq!(slugify!("the hello world"), "the-hello-world");
+/// ```
+pub fn with_default_config<R>(config: SlugConfig, f: impl FnOnce() -> R) -> R {
+    struct Restore(Option<SlugConfig>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            let previous = self.0.take();
+            DEFAULT_CONFIG.with(|default| *default.borrow_mut() = previous);
+        }
+    }
+
+    let _restore = Restore(DEFAULT_CONFIG.with(|default| default.replace(Some(config))));
+    f()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        clear_default_config, default_config, set_default_config, slugify, with_default_config,
+        SlugConfig,
+    };
+
+    fn underscore_config() -> SlugConfig {
+        SlugConfig {
+            separator: String::from("_"),
+            ..SlugConfig::default()
+        }
+    }
+
+    #[test]
+    fn test_set_default_config() {
+        set_default_config(underscore_config());
+        assert_eq!(slugify!("hello world"), "hello_world");
+        assert_eq!(slugify!("hello world", max_length = 7), "hello_w");
+        // the positional function does not use the default config
+        assert_eq!(
+            slugify("hello world", "", "-", None, false, 5),
+            "hello-world"
+        );
+
+        clear_default_config();
+        assert_eq!(slugify!("hello world"), "hello-world");
+    }
+
+    #[test]
+    fn test_default_config_value() {
+        assert_eq!(default_config(), SlugConfig::default());
+        set_default_config(underscore_config());
+        assert_eq!(default_config(), underscore_config());
+        clear_default_config();
+        assert_eq!(default_config(), SlugConfig::default());
+    }
+
+    #[test]
+    fn test_with_default_config() {
+        assert_eq!(
+            with_default_config(underscore_config(), || slugify!("hello world")),
+            "hello_world"
+        );
+        assert_eq!(slugify!("hello world"), "hello-world");
+
+        let result = std::panic::catch_unwind(|| {
+            with_default_config(underscore_config(), || panic!("boom"))
+        });
+        assert!(result.is_err());
+        assert_eq!(slugify!("hello world"), "hello-world");
+    }
+
+    #[test]
+    fn test_default_config_is_per_thread() {
+        set_default_config(underscore_config());
+        let other = std::thread::spawn(|| slugify!("hello world"))
+            .join()
+            .unwrap();
+        assert_eq!(other, "hello-world");
+        clear_default_config();
+    }
+}
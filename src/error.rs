@@ -0,0 +1,26 @@
+use std::fmt;
+
+/// Errors returned by the slug helpers that can fail to produce a usable slug.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SlugError {
+    /// The input did not contain anything that survives slugification.
+    EmptyResult,
+    /// The slug breaks a naming rule of the target system; the message says which one.
+    InvalidResult(String),
+    /// No slug accepted by the uniqueness check was found in the allowed number of attempts.
+    NotUnique,
+}
+
+impl fmt::Display for SlugError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SlugError::EmptyResult => write!(f, "the generated slug is empty"),
+            SlugError::NotUnique => write!(f, "no unique slug was found"),
+            SlugError::InvalidResult(reason) => {
+                write!(f, "the generated slug is invalid: {}", reason)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SlugError {}
@@ -25,7 +25,7 @@
 //!  and this to your crate root:
 //!
 //!```rust,ignore
-//!use slugify-rs::slugify;
+//!use slugify_rs::slugify;
 //!```
 //!
 //!# Examples
@@ -33,7 +33,7 @@
 //!## Basic slug generation
 //!
 //!```rust
-//! # use slugify-rs::slugify;
+//! # use slugify_rs::slugify;
 //! # fn main() {
 //!assert_eq!(slugify!("hello world"), "hello-world");
 //! # }
@@ -56,6 +56,7 @@
 //! # use slugify_rs::slugify;
 //! # fn main() {
 //!assert_eq!(slugify!("the quick brown fox jumps over the lazy dog", stop_words = "the,fox"), "quick-brown-jumps-over-lazy-dog");
+//!assert_eq!(slugify!("the quick brown fox jumps over the lazy dog", stop_words = ["the", "fox"]), "quick-brown-jumps-over-lazy-dog");
 //! # }
 //!```
 //!
@@ -69,19 +70,39 @@
 //! # }
 //!```
 //!
+//!## Minimum length
+//!
+//! Slugs shorter than `min_length` are padded with `0`, but never beyond `max_length`.
+//!
+//!```rust
+//! # use slugify_rs::slugify;
+//! # fn main() {
+//!assert_eq!(slugify!("hi", min_length = 5), "hi000");
+//!assert_eq!(slugify!("hi", min_length = 5, max_length = 20), "hi000");
+//!assert_eq!(slugify!("hi", min_length = 5, max_length = 4), "hi00");
+//! # }
+//!```
+//!
 //!## Random values added to string through nanoid
 //! ```rust
 //! # use slugify_rs::slugify;
 //! # // Default randomness is of 5 characters
 //! # fn main() {
-//! assert_eq!(slugify!("hello world", randomness=true).len(), "hello-world".len()+5);
+//! assert_eq!(slugify!("hello world", randomness=true).len(), "hello-world".len()+1+5);
 //! # }
 //! ```
 //! ```rust
 //! # use slugify_rs::slugify;
 //! # // You can also add custom length to the randomness
 //! # fn main() {
-//! assert_eq!(slugify!("hello world", randomness=true,randomness_length=8).len(), "hello-world".len()+8);
+//! assert_eq!(slugify!("hello world", randomness=true,randomness_length=8).len(), "hello-world".len()+1+8);
+//! # }
+//! ```
+//! ```rust
+//! # use slugify_rs::slugify;
+//! # // The random component can also be put in front of the slug
+//! # fn main() {
+//! assert!(slugify!("hello world", randomness=true, randomness_prefix=true).ends_with("-hello-world"));
 //! # }
 //! ```
 //!## Phonetic Conversion and accented text
@@ -95,11 +116,62 @@
 //! # }
 //!```
 //!
+//!## Preserving hyphens from the input
+//!
+//! With `preserve_hyphens = true` hyphens that are already in the input are kept as-is, whatever
+//! separator is used between the other words.
+//!
+//!```rust
+//! # use slugify_rs::slugify;
+//! # fn main() {
+//!assert_eq!(slugify!("Coca-Cola Zero", separator = "_"), "coca_cola_zero");
+//!assert_eq!(slugify!("Coca-Cola Zero", separator = "_", preserve_hyphens = true), "coca-cola_zero");
+//! # }
+//!```
+//!
+//! With `preserve_separator = true` every hyphen of the input becomes a separator of its own,
+//! so the structure of an already slugified text survives.
+//!
+//!```rust
+//! # use slugify_rs::slugify;
+//! # fn main() {
+//!assert_eq!(slugify!("hello--world", separator = "_"), "hello_world");
+//!assert_eq!(slugify!("hello--world", separator = "_", preserve_separator = true), "hello__world");
+//! # }
+//!```
+//!
+//!## Preserving acronyms
+//!
+//!```rust
+//! # use slugify_rs::slugify;
+//! # fn main() {
+//!assert_eq!(slugify!("NASA's new HTML parser", preserve_acronyms = true), "NASA-s-new-HTML-parser");
+//! # }
+//!```
+//!
+//!## Allowing extra characters
+//!
+//!```rust
+//! # use slugify_rs::slugify;
+//! # fn main() {
+//!assert_eq!(slugify!("Release 1.0.2", allow_chars = ['.']), "release-1.0.2");
+//! # }
+//!```
+//!
+//!## Overriding the transliteration of characters
+//!
+//!```rust
+//! # use slugify_rs::slugify;
+//! # fn main() {
+//!assert_eq!(slugify!("Müller & Söhne", transliteration_overrides = [('ü', "ue"), ('ö', "oe")]), "mueller-soehne");
+//! # }
+//!```
+//!
 //!## Passing multiple optional parameters.
 //!
-//! **NOTE:** the order of optional parameters matters: **stop_words**, **separator**
-//! and then **max_length**. All of them are optional, however when specifying more than one optional parameter, this
-//! order must be adhered.
+//! All optional parameters are passed as `name = value` pairs and can be given in any order. The
+//! text itself can be named too, as `text = ...`, as long as it comes first, and a trailing comma
+//! is allowed.
 //!
 //!```rust
 //! # use slugify_rs::slugify;
@@ -107,186 +179,901 @@
 //!assert_eq!(slugify!("the hello world", stop_words = "the", separator = "-"), "hello-world");
 //!assert_eq!(slugify!("the hello world", separator = ".", max_length = 10), "the.hello");
 //!assert_eq!(slugify!("the hello world", stop_words = "the", max_length = 5), "hello");
-//!assert_eq!(slugify!("the hello world", stop_words = "the", separator = "-", max_length = 20), "hello-world");
+//!assert_eq!(slugify!("the hello world", max_length = 20, separator = "-", stop_words = "the"), "hello-world");
+//!assert_eq!(slugify!(text = "the hello world", separator = "_"), "the_hello_world");
+//!assert_eq!(slugify!("the hello world", separator = "_",), "the_hello_world");
 //! # }
 //!```
 //!
-use deunicode::deunicode;
+use deunicode::{deunicode, deunicode_char};
+use std::collections::HashMap;
 
-#[macro_export]
-macro_rules! slugify {
-    ($text:expr) => {{
-        slugify($text, "", "-", None, false, 5)
-    }};
-    // with a boolean
-    ($text:expr, randomness=$bool:expr) => {{
-        slugify($text, "", "-", None, $bool, 5)
-    }};
+use redact::Audit;
 
-    ($text:expr, randomness=$bool:expr,randomness_length=$usize:expr) => {{
-        slugify($text, "", "-", None, $bool, $usize)
-    }};
+mod collisions;
+mod compare;
+mod defaults;
+mod error;
+mod lines;
+#[cfg(feature = "numbers-to-words")]
+mod numbers;
+mod path;
+mod redact;
+mod sluggable;
+mod sql_keywords;
+mod stats;
+mod stop_words;
+mod targets;
+mod truncate;
+mod version;
+mod writer;
 
-    ($text:expr, stop_words=$stopwords:expr) => {{
-        slugify($text, $stopwords, "-", None, false, 5)
-    }};
+pub use collisions::{slugify_count_collisions, slugify_unique};
+pub use compare::{slug_diff, slug_eq, slug_eq_case_sensitive, SlugDiff};
+pub use defaults::{clear_default_config, default_config, set_default_config, with_default_config};
+pub use error::SlugError;
+pub use lines::slugify_lines;
+pub use path::{slugify_from_path_components, slugify_os_str};
+pub use redact::{
+    slugify_dry_run, slugify_redact, slugify_with_metadata, Redaction, SlugMetadata, SlugTrace,
+};
+pub use sluggable::{slugify_from, Sluggable};
+pub use stats::{slug_stats, SlugStats};
+pub use stop_words::IntoStopWords;
+pub use targets::{
+    slugify_for_docker_tag, slugify_for_github_repo, slugify_for_kubernetes,
+    slugify_for_npm_package, slugify_for_npm_scoped, slugify_from_csv_header,
+    slugify_from_csv_headers, slugify_sql_identifier, SqlEngine,
+};
+pub use truncate::{slugify_truncate_at_separator, TruncationStrategy};
+pub use version::{slugify_version, slugify_version_with_separator};
+pub use writer::SlugWriter;
 
-    ($text:expr, stop_words=$stopwords:expr,randomness=$bool:expr) => {{
-        slugify($text, $stopwords, "-", None, $bool, 5)
+/// Generates a slug from a text, taking the options of [`SlugConfig`] as `name = value` pairs.
+///
+/// The text is anything implementing `AsRef<str>`. Literals of other types, like integers
+/// (`slugify!(2024)`), floats, `bool` and `char`, are converted with `to_string()` first.
+///
+/// An empty string literal is rejected at compile time, as it can only produce an empty slug:
+///
+/// ```compile_fail
+/// # use slugify_rs::slugify;
+/// let slug = slugify!("", separator = "_");
+/// ```
+#[macro_export]
+macro_rules! slugify {
+    ("" $(, $option:ident = $value:expr)* $(,)?) => {
+        compile_error!("slugify! called with empty string literal")
+    };
+    (text = "" $(, $option:ident = $value:expr)* $(,)?) => {
+        compile_error!("slugify! called with empty string literal")
+    };
+    ($text:literal $(, $option:ident = $value:expr)* $(,)?) => {
+        $crate::slugify!(&::std::string::ToString::to_string(&$text) $(, $option = $value)*)
+    };
+    (text = $text:expr $(, $option:ident = $value:expr)* $(,)?) => {
+        $crate::slugify!($text $(, $option = $value)*)
+    };
+    ($text:expr $(, $option:ident = $value:expr)* $(,)?) => {{
+        #[allow(unused_mut)]
+        let mut config = $crate::default_config();
+        $($crate::__slugify_option!(config, $option, $value);)*
+        $crate::slugify_with_config($text, &config)
     }};
+}
 
-    ($text:expr, stop_words=$stopwords:expr,randomness=$bool:expr,randomness_length=$usize:expr) => {{
-        slugify($text, $stopwords, "-", None, $bool, $usize)
+/// Lazily slugifies every item of an iterator of `&str` or `String`, accepting the same options
+/// as `slugify!`.
+///
+/// ```rust
+/// # use slugify_rs::slugify_iter;
+/// let titles = vec!["Hello World", "The Other Post"];
+/// let slugs: Vec<String> = slugify_iter!(titles, separator = "_").collect();
+/// assert_eq!(slugs, ["hello_world", "the_other_post"]);
+/// ```
+#[macro_export]
+macro_rules! slugify_iter {
+    ($iter:expr $(, $option:ident = $value:expr)* $(,)?) => {{
+        #[allow(unused_mut)]
+        let mut config = $crate::default_config();
+        $($crate::__slugify_option!(config, $option, $value);)*
+        ::std::iter::IntoIterator::into_iter($iter)
+            .map(move |text| $crate::slugify_with_config(text, &config))
     }};
+}
 
-    ($text:expr, separator=$sep:expr) => {{
-        slugify($text, "", $sep, None, false, 5)
-    }};
+// Applies a single `name = value` option of the `slugify!` macro to a `SlugConfig`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __slugify_option {
+    ($config:ident, stop_words, $value:expr) => {
+        $config.stop_words = $crate::IntoStopWords::into_stop_words($value)
+    };
+    ($config:ident, separator, $value:expr) => {
+        $config.separator = ::std::string::String::from($value)
+    };
+    ($config:ident, max_length, $value:expr) => {
+        $config.max_length = Some($value)
+    };
+    ($config:ident, min_length, $value:expr) => {
+        $config.min_length = Some($value)
+    };
+    ($config:ident, strip_leading_digits, $value:expr) => {
+        $config.strip_leading_digits = $value
+    };
+    ($config:ident, randomness, $value:expr) => {
+        $config.randomness = $value
+    };
+    ($config:ident, randomness_prefix, $value:expr) => {
+        $config.randomness_prefix = $value
+    };
+    ($config:ident, randomness_length, $value:expr) => {
+        $config.randomness_length = $value
+    };
+    ($config:ident, input_max_length, $value:expr) => {
+        $config.input_max_length = Some($value)
+    };
+    ($config:ident, preserve_hyphens, $value:expr) => {
+        $config.preserve_hyphens = $value
+    };
+    ($config:ident, preserve_separator, $value:expr) => {
+        $config.preserve_separator = $value
+    };
+    ($config:ident, empty_fallback, $value:expr) => {
+        $config.empty_fallback = Some(::std::string::String::from($value))
+    };
+    ($config:ident, preserve_acronyms, $value:expr) => {
+        $config.preserve_acronyms = $value
+    };
+    ($config:ident, seed, $value:expr) => {
+        $config.seed = Some($value)
+    };
+    ($config:ident, numbers_to_words, $value:expr) => {
+        $config.numbers_to_words = $value
+    };
+    ($config:ident, allow_chars, $value:expr) => {
+        $config.allow_chars = ::std::vec::Vec::from($value)
+    };
+    ($config:ident, segment_separator, $value:expr) => {
+        $config.segment_separator = Some(::std::string::String::from($value))
+    };
+    ($config:ident, truncation, $value:expr) => {
+        $config.truncation = $value
+    };
+    ($config:ident, url_encode, $value:expr) => {
+        $config.url_encode = $value
+    };
+    ($config:ident, deunicode_fallback, $value:expr) => {
+        $config.deunicode_fallback = Some(::std::string::String::from($value))
+    };
+    ($config:ident, strip_bidi_controls, $value:expr) => {
+        $config.strip_bidi_controls = $value
+    };
+    ($config:ident, transliteration_overrides, $value:expr) => {
+        $config.transliteration_overrides = ::std::iter::IntoIterator::into_iter($value)
+            .map(|(c, s)| (c, ::std::string::String::from(s)))
+            .collect()
+    };
+}
 
-    ($text:expr, separator=$sep:expr,randomness=$bool:expr) => {{
-        slugify($text, "", $sep, None, $bool, 5)
-    }};
+/// The options used to generate a slug, as set through the `slugify!` macro.
+///
+/// The macro starts from the default config of the current thread, see [`set_default_config`].
+/// A config is `Send` and `Sync`, so one instance can be shared between threads behind an `Arc`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SlugConfig {
+    /// Words removed from the input, matched against the lowercased input so they should be
+    /// lowercase themselves. The `slugify!` macro and [`slugify`] lowercase them for you.
+    pub stop_words: Vec<String>,
+    /// Separator placed between words. Runs of whitespace, punctuation and removed stop words
+    /// collapse into a single separator, and none is left at either end of the slug. It may be
+    /// longer than one character, or a letter or digit, without being confused with the text.
+    pub separator: String,
+    /// Separator placed between the segments of the input, the parts split by `.` or `/`, while
+    /// `separator` is used between the words of a segment. By default segments are not told
+    /// apart from words.
+    pub segment_separator: Option<String>,
+    /// Maximum length of the slug, not counting the random suffix.
+    pub max_length: Option<usize>,
+    /// How a slug longer than `max_length` is shortened.
+    pub truncation: TruncationStrategy,
+    /// Minimum length of the slug, shorter slugs are padded with `0`. Padding stops at
+    /// `max_length` if that is smaller.
+    pub min_length: Option<usize>,
+    /// Remove digits, and the separators following them, from the start of the slug so it starts
+    /// with a letter. `max_length` applies to what is left.
+    pub strip_leading_digits: bool,
+    /// Whether a random suffix is appended to the slug.
+    pub randomness: bool,
+    /// Number of random characters appended after the separator, `0` appends nothing.
+    pub randomness_length: usize,
+    /// Put the random component in front of the slug instead of at the end.
+    pub randomness_prefix: bool,
+    /// Seed for the random component, so the same text and seed always give the same slug, in
+    /// every release of the crate. This needs the `deterministic` feature, without it the seed is
+    /// ignored and the random component stays random.
+    pub seed: Option<u64>,
+    /// Maximum number of characters of the input that are looked at.
+    pub input_max_length: Option<usize>,
+    /// Keep hyphens found in the input as-is instead of replacing them with the separator.
+    pub preserve_hyphens: bool,
+    /// Replace every hyphen found in the input with a separator of its own, instead of collapsing
+    /// runs of hyphens into one. Dashes transliterated to hyphens count too, so `—` gives two
+    /// separators. `preserve_hyphens` takes precedence.
+    pub preserve_separator: bool,
+    /// Keep runs of two or more uppercase letters of the input, like `HTML`, in uppercase.
+    pub preserve_acronyms: bool,
+    /// Spell out the numbers from 0 to 999 in English words. This needs the `numbers-to-words`
+    /// feature, without it the option has no effect.
+    pub numbers_to_words: bool,
+    /// Extra characters that are kept in the slug as-is, like `.` in version numbers. Non-ASCII
+    /// characters listed here are not transliterated either.
+    pub allow_chars: Vec<char>,
+    /// Replacements used instead of the built-in transliteration for single characters, like
+    /// `ü` to `ue`. The replacement goes through the rest of the pipeline as regular text.
+    pub transliteration_overrides: HashMap<char, String>,
+    /// Replacement for characters that have no transliteration at all, like private use
+    /// characters. By default they are dropped and split the word they are in; an empty string
+    /// drops them without splitting it.
+    pub deunicode_fallback: Option<String>,
+    /// Remove invisible bidirectional control characters, like the right-to-left mark, before
+    /// transliterating. When turned off they go through `deunicode` and `allow_chars` like any
+    /// other character.
+    pub strip_bidi_controls: bool,
+    /// Used as the slug when nothing of the input survives, instead of an empty string.
+    pub empty_fallback: Option<String>,
+    /// Percent-encode everything but the URL unreserved characters (`A-Z a-z 0-9 - . _ ~`) in the
+    /// final slug, for separators or allowed characters that are not URL safe.
+    pub url_encode: bool,
+}
 
-    ($text:expr, separator=$sep:expr,randomness=$bool:expr,randomness_length=$usize:expr) => {{
-        slugify($text, "", $sep, None, $bool, $usize)
-    }};
-    ($text:expr, max_length=$len:expr) => {{
-        slugify($text, "", "-", Some($len), false, 5)
-    }};
+impl Default for SlugConfig {
+    fn default() -> Self {
+        SlugConfig {
+            stop_words: Vec::new(),
+            separator: String::from("-"),
+            segment_separator: None,
+            max_length: None,
+            truncation: TruncationStrategy::ExactChar,
+            min_length: None,
+            strip_leading_digits: false,
+            randomness: false,
+            randomness_length: 5,
+            randomness_prefix: false,
+            seed: None,
+            input_max_length: None,
+            preserve_hyphens: false,
+            preserve_separator: false,
+            preserve_acronyms: false,
+            numbers_to_words: false,
+            allow_chars: Vec::new(),
+            transliteration_overrides: HashMap::new(),
+            deunicode_fallback: None,
+            strip_bidi_controls: true,
+            empty_fallback: None,
+            url_encode: false,
+        }
+    }
+}
 
-    ($text:expr, max_length=$len:expr,randomness=$bool:expr) => {{
-        slugify($text, "", "-", Some($len), $bool, 5)
-    }};
+impl SlugConfig {
+    /// Generates a slug from `text` with this config, so one config can be reused for many texts.
+    ///
+    /// ```rust
+    /// # use slugify_rs::SlugConfig;
+    /// let config = SlugConfig {
+    ///     separator: String::from("_"),
+    ///     ..SlugConfig::default()
+    /// };
+    /// assert_eq!(config.generate("Hello World"), "hello_world");
+    /// assert_eq!(config.generate("Another Title"), "another_title");
+    /// ```
+    pub fn generate(&self, text: impl AsRef<str>) -> String {
+        slugify_with_config(text, self)
+    }
+}
 
-    ($text:expr, max_length=$len:expr,randomness=$bool:expr,randomness_length=$usize:expr) => {{
-        slugify($text, "", "-", Some($len), $bool, $usize)
-    }};
+pub fn slugify(
+    string: impl AsRef<str>,
+    stop_words: &str,
+    sep: &str,
+    max_length: Option<usize>,
+    randomness: bool,
+    randomness_length: usize,
+) -> String {
+    slugify_with_config(
+        string,
+        &SlugConfig {
+            stop_words: stop_words.into_stop_words(),
+            separator: sep.to_owned(),
+            max_length,
+            randomness,
+            randomness_length,
+            ..SlugConfig::default()
+        },
+    )
+}
 
-    ($text:expr, stop_words=$stopwords:expr, separator=$sep:expr) => {{
-        slugify($text, $stopwords, $sep, None, false, 5)
-    }};
+/// Generates a slug from `string` using the options in `config`.
+pub fn slugify_with_config(string: impl AsRef<str>, config: &SlugConfig) -> String {
+    slugify_audited(string.as_ref(), config, None)
+}
 
-    ($text:expr, stop_words=$stopwords:expr, separator=$sep:expr,randomness=$bool:expr) => {{
-        slugify($text, $stopwords, $sep, None, $bool, 5)
-    }};
+/// [`slugify_with_config`], recording what happens to the input in `audit`.
+fn slugify_audited(string: &str, config: &SlugConfig, mut audit: Option<&mut Audit>) -> String {
+    let mut s = slug_body(string, config, audit.as_deref_mut());
+    if s.is_empty() {
+        if let Some(fallback) = &config.empty_fallback {
+            s.push_str(fallback);
+        }
+    }
+    pad(&mut s, config);
+    let s = finish(s, config, audit.as_deref_mut());
+    Audit::step(&mut audit, "output", &s);
+    s
+}
 
-    ($text:expr, stop_words=$stopwords:expr, separator=$sep:expr,randomness=$bool:expr,randomness_length=$usize:expr) => {{
-        slugify($text, $stopwords, $sep, None, $bool, $usize)
-    }};
+/// Like [`slugify_with_config`], but fails with [`SlugError::EmptyResult`] when nothing of
+/// `string` survives and no `empty_fallback` is configured.
+///
+/// ```rust
+/// # use slugify_rs::{try_slugify_with_config, SlugConfig, SlugError};
+/// let config = SlugConfig::default();
+/// assert_eq!(try_slugify_with_config("hello world", &config), Ok(String::from("hello-world")));
+/// assert_eq!(try_slugify_with_config("---", &config), Err(SlugError::EmptyResult));
+/// ```
+pub fn try_slugify_with_config(
+    string: impl AsRef<str>,
+    config: &SlugConfig,
+) -> Result<String, SlugError> {
+    let mut s = slug_body(string.as_ref(), config, None);
+    if s.is_empty() {
+        match &config.empty_fallback {
+            Some(fallback) => s.push_str(fallback),
+            None => return Err(SlugError::EmptyResult),
+        }
+    }
+    pad(&mut s, config);
+    Ok(finish(s, config, None))
+}
 
-    ($text:expr, stop_words=$stopwords:expr, max_length=$len:expr) => {{
-        slugify($text, $stopwords, "-", Some($len), false, 5)
-    }};
+/// Generates a slug from an optional text, like a nullable title column. `None` is treated like
+/// an empty text, so it gives an empty slug unless an `empty_fallback` is configured.
+///
+/// ```rust
+/// # use slugify_rs::{slugify_opt, SlugConfig};
+/// let config = SlugConfig::default();
+/// assert_eq!(slugify_opt(Some("Hello World"), &config), "hello-world");
+/// assert_eq!(slugify_opt(None::<&str>, &config), "");
+/// ```
+pub fn slugify_opt(s: Option<impl AsRef<str>>, config: &SlugConfig) -> String {
+    match s {
+        Some(s) => slugify_with_config(s, config),
+        None => slugify_with_config("", config),
+    }
+}
 
-    ($text:expr, stop_words=$stopwords:expr, max_length=$len:expr,randomness=$bool:expr) => {{
-        slugify($text, $stopwords, "-", Some($len), $bool, 5)
-    }};
+/// Generates a slug from `s` with the default options, returning `default` as-is when the slug
+/// would be empty.
+///
+/// ```rust
+/// # use slugify_rs::slugify_or_default;
+/// assert_eq!(slugify_or_default("Hello World", "untitled"), "hello-world");
+/// assert_eq!(slugify_or_default("???", "untitled"), "untitled");
+/// ```
+pub fn slugify_or_default(s: &str, default: &str) -> String {
+    let slug = slugify_with_config(s, &SlugConfig::default());
+    if slug.is_empty() {
+        default.to_owned()
+    } else {
+        slug
+    }
+}
 
-    ($text:expr, stop_words=$stopwords:expr, max_length=$len:expr,randomness=$bool:expr,randomness_length=$usize:expr) => {{
-        slugify($text, $stopwords, "-", Some($len), $bool, $usize)
-    }};
+/// Appends the slug of `s` to `buf`, to build composite slugs in a single buffer.
+///
+/// ```rust
+/// # use slugify_rs::{slugify_to_string, SlugConfig};
+/// let config = SlugConfig::default();
+/// let mut buf = String::new();
+/// slugify_to_string("Rust News", &config, &mut buf);
+/// buf.push('/');
+/// slugify_to_string("Hello World", &config, &mut buf);
+/// assert_eq!(buf, "rust-news/hello-world");
+/// ```
+pub fn slugify_to_string(s: &str, config: &SlugConfig, buf: &mut String) {
+    buf.push_str(&slugify_with_config(s, config));
+}
 
-    ($text:expr, separator=$sep:expr, max_length=$len:expr) => {{
-        slugify($text, "", $sep, Some($len), false, 5)
-    }};
+/// Marks a separator in the slug being built, it never appears in the output of `deunicode`.
+const SEP: char = '\u{E000}';
+const SEP_STR: &str = "\u{E000}";
+/// Marks a segment separator in the slug being built.
+const SEGMENT_SEP: char = '\u{E001}';
 
-    ($text:expr, separator=$sep:expr, max_length=$len:expr,randomness=$bool:expr) => {{
-        slugify($text, "", $sep, Some($len), $bool, 5)
-    }};
+fn slug_body(string: &str, config: &SlugConfig, mut audit: Option<&mut Audit>) -> String {
+    if let (TruncationStrategy::SentenceBoundary, Some(max_length)) =
+        (config.truncation, config.max_length)
+    {
+        return sentence_truncated(string, max_length, config, audit);
+    }
 
-    ($text:expr, separator=$sep:expr, max_length=$len:expr,randomness=$bool:expr,randomness_length=$usize:expr) => {{
-        slugify($text, "", $sep, Some($len), $bool, $usize)
-    }};
+    // only look at the first `input_max_length` characters of the input, cutting on a char boundary
+    let string = match config
+        .input_max_length
+        .and_then(|n| string.char_indices().nth(n))
+    {
+        Some((idx, _)) => &string[..idx],
+        None => string,
+    };
+    Audit::step(&mut audit, "input", string);
 
-    ($text:expr, stop_words=$stopwords:expr, separator=$sep:expr, max_length=$len:expr) => {{
-        slugify($text, $stopwords, $sep, Some($len), false, 5)
-    }};
+    // apply the user's transliteration overrides and deunicode fallback, drop invisible
+    // bidirectional control characters and the Cyrillic hard and soft signs (which deunicode
+    // turns into apostrophes that would split the word) before transliterating, and keep characters that transliterate to whole
+    // words (like CJK ideographs) from sticking to a preceding latin letter or digit
+    let mut prepared = String::with_capacity(string.len());
+    for c in string.chars() {
+        if let Some(replacement) = config.transliteration_overrides.get(&c) {
+            Audit::record(&mut audit, || Redaction::Transliterated {
+                from: c.to_string(),
+                to: replacement.clone(),
+            });
+            prepared.push_str(replacement);
+            continue;
+        }
+        if (config.strip_bidi_controls && is_bidi_control(c)) || is_cyrillic_sign(c) {
+            Audit::record(&mut audit, || Redaction::CharStripped(c));
+            continue;
+        }
+        if let Some(fallback) = &config.deunicode_fallback {
+            if !c.is_ascii() && deunicode_char(c).is_none() {
+                Audit::record(&mut audit, || Redaction::Transliterated {
+                    from: c.to_string(),
+                    to: fallback.clone(),
+                });
+                prepared.push_str(fallback);
+                continue;
+            }
+        }
+        if !c.is_ascii() && !config.allow_chars.contains(&c) {
+            Audit::record(&mut audit, || Redaction::Transliterated {
+                from: c.to_string(),
+                to: deunicode_char(c).unwrap_or("[?]").trim().to_owned(),
+            });
+        }
+        if transliterates_to_word(c) && prepared.ends_with(|p: char| p.is_ascii_alphanumeric()) {
+            prepared.push(' ');
+        }
+        prepared.push(c);
+    }
+    let string = prepared;
 
-    ($text:expr, stop_words=$stopwords:expr, separator=$sep:expr, max_length=$len:expr,randomness=$bool:expr) => {{
-        slugify($text, $stopwords, $sep, Some($len), $bool, 5)
-    }};
+    let string = transliterate(&string, &config.allow_chars);
+    #[cfg(feature = "numbers-to-words")]
+    let string = if config.numbers_to_words {
+        numbers::numbers_to_words(&string)
+    } else {
+        string
+    };
+    Audit::step(&mut audit, "deunicode", &string);
 
-    ($text:expr, stop_words=$stopwords:expr, separator=$sep:expr, max_length=$len:expr,randomness=$bool:expr,randomness_length=$usize:expr) => {{
-        slugify($text, $stopwords, $sep, Some($len), $bool, $usize)
-    }};
-}
+    // separators are marked with placeholders until the end, so that a separator which is also
+    // a letter or digit is never mistaken for one found in the text
+    let segment_sep = config.segment_separator.as_deref();
+    let materialize = |s: &str| -> String {
+        let mut out = String::with_capacity(s.len());
+        for c in s.chars() {
+            match c {
+                SEP => out.push_str(&config.separator),
+                SEGMENT_SEP => out.push_str(segment_sep.unwrap_or_default()),
+                c => out.push(c),
+            }
+        }
+        out
+    };
 
-pub fn slugify(
-    string: &str,
-    stop_words: &str,
-    sep: &str,
-    max_length: Option<usize>,
-    randomness: bool,
-    randomness_length: usize,
-) -> String {
-    let char_vec: Vec<char> = sep.chars().collect();
-    let mut string: String = deunicode(string.into())
-        .to_lowercase()
+    let mut string: String = to_lowercase(&string, config.preserve_acronyms)
         .trim()
-        .trim_matches(match char_vec.get(0) {
-            Some(a) => a.to_owned(),
-            None => ' ',
-        })
-        .replace(' ', &sep.to_string());
-
-    // remove stop words
-    for word in stop_words.split(",") {
-        if !word.is_empty() {
-            string = string.replace(word, &sep.to_string());
+        .replace(' ', SEP_STR);
+
+    // remove stop words, ignoring the case of acronyms kept in uppercase
+    for word in &config.stop_words {
+        if word.is_empty() {
+            continue;
         }
+        let removed = if config.preserve_acronyms {
+            replace_ignoring_ascii_case(&string, word, SEP_STR)
+        } else if string.contains(word.as_str()) {
+            Some(string.replace(word.as_str(), SEP_STR))
+        } else {
+            None
+        };
+        if let Some(removed) = removed {
+            Audit::record(&mut audit, || Redaction::StopWordRemoved(word.clone()));
+            string = removed;
+        }
+    }
+
+    if audit.is_some() {
+        Audit::step(&mut audit, "stop_words", &materialize(&string));
     }
 
-    let mut slug = Vec::with_capacity(string.len());
+    let mut slug = String::with_capacity(string.len());
 
     let mut is_sep = true;
+    // whether the separator at the end of the slug stands for a hyphen kept by `preserve_separator`
+    let mut is_hyphen_sep = false;
 
     for x in string.chars() {
         match x {
             'a'..='z' | '0'..='9' => {
                 is_sep = false;
-                slug.push(x as u8);
+                is_hyphen_sep = false;
+                slug.push(x);
+            }
+            // only acronyms are left uppercase at this point
+            'A'..='Z' if config.preserve_acronyms => {
+                is_sep = false;
+                is_hyphen_sep = false;
+                slug.push(x);
+            }
+            SEP | SEGMENT_SEP => {
+                if !is_sep {
+                    is_sep = true;
+                    slug.push(SEP);
+                }
+            }
+            _ if config.allow_chars.contains(&x) => {
+                is_sep = false;
+                is_hyphen_sep = false;
+                slug.push(x);
+            }
+            '.' | '/' if segment_sep.is_some() => {
+                // a segment separator replaces the word separator before it
+                if slug.ends_with(SEP) {
+                    slug.pop();
+                }
+                if !slug.is_empty() && !slug.ends_with(SEGMENT_SEP) {
+                    slug.push(SEGMENT_SEP);
+                }
+                is_sep = true;
+            }
+            '-' if config.preserve_hyphens => {
+                if !is_sep {
+                    is_sep = true;
+                    slug.push('-');
+                }
+            }
+            '-' if config.preserve_separator => {
+                // every hyphen gets a separator of its own, other separators run into it
+                if slug.ends_with(SEP) && !is_hyphen_sep {
+                    slug.pop();
+                }
+                if !slug.is_empty() {
+                    slug.push(SEP);
+                }
+                is_sep = true;
+                is_hyphen_sep = true;
             }
             _ => {
+                if !config.separator.starts_with(x) {
+                    Audit::record(&mut audit, || Redaction::CharStripped(x));
+                }
                 if !is_sep {
                     is_sep = true;
-                    slug.push(char_vec[0] as u8);
-                } else {
+                    slug.push(SEP);
                 }
             }
         }
     }
 
-    if char_vec.len() > 0 && slug.last() == Some(&(char_vec[0] as u8)) {
-        slug.pop();
+    let is_trailing_sep =
+        |c: char| c == SEP || c == SEGMENT_SEP || (config.preserve_hyphens && c == '-');
+
+    slug.truncate(slug.trim_end_matches(is_trailing_sep).len());
+    if audit.is_some() {
+        Audit::step(&mut audit, "main_loop", &materialize(&slug));
+    }
+
+    let mut s = slug;
+
+    // done before truncating so max_length applies to what is left
+    if config.strip_leading_digits {
+        let stripped = s.trim_start_matches(|c: char| c.is_ascii_digit() || is_trailing_sep(c));
+        for c in s[..s.len() - stripped.len()].chars() {
+            if c.is_ascii_digit() {
+                Audit::record(&mut audit, || Redaction::CharStripped(c));
+            }
+        }
+        s = stripped.to_string();
+    }
+
+    let s = if let Some(max_length) = config.max_length {
+        // lengths are counted in the final slug, where the separators may be any size
+        let output_len = |c: char| match c {
+            SEP => config.separator.len(),
+            SEGMENT_SEP => segment_sep.map_or(0, str::len),
+            c => c.len_utf8(),
+        };
+        let untruncated: usize = s.chars().map(output_len).sum();
+
+        let mut len = 0;
+        let mut cut = s.len();
+        for (i, c) in s.char_indices() {
+            len += output_len(c);
+            if len > max_length {
+                cut = i;
+                break;
+            }
+        }
+        if config.truncation == TruncationStrategy::WordBoundary && cut < s.len() {
+            // the separator at `cut` itself may still be cut off
+            if let Some((i, _)) = s
+                .char_indices()
+                .take_while(|&(i, _)| i <= cut)
+                .filter(|&(i, c)| i > 0 && is_trailing_sep(c))
+                .last()
+            {
+                cut = i;
+            }
+        }
+        s.truncate(cut);
+        if config.truncation != TruncationStrategy::NoBoundary {
+            s.truncate(s.trim_end_matches(is_trailing_sep).len());
+        }
+
+        let s = materialize(&s);
+        if s.len() < untruncated {
+            Audit::record(&mut audit, || Redaction::Truncated {
+                from: untruncated,
+                to: s.len(),
+            });
+        }
+        s
+    } else {
+        materialize(&s)
+    };
+
+    Audit::step(&mut audit, "truncation", &s);
+    s
+}
+
+/// Pads a slug shorter than `min_length` with `0`, but never beyond `max_length`.
+fn pad(s: &mut String, config: &SlugConfig) {
+    if let Some(min_length) = config.min_length {
+        let min_length = config
+            .max_length
+            .map_or(min_length, |max| min_length.min(max));
+        while s.len() < min_length {
+            s.push('0');
+        }
+    }
+}
+
+/// Generates the slug of the longest run of whole sentences of `string` that fits in
+/// `max_length`, or cuts the whole slug at a word boundary when not even the first one fits.
+fn sentence_truncated(
+    string: &str,
+    max_length: usize,
+    config: &SlugConfig,
+    mut audit: Option<&mut Audit>,
+) -> String {
+    let mut unlimited = config.clone();
+    unlimited.max_length = None;
+
+    let mut chars = string.char_indices().peekable();
+    let mut ends = vec![string.len()];
+    while let Some((i, c)) = chars.next() {
+        let at_break = chars.peek().map_or(true, |&(_, next)| next.is_whitespace());
+        if matches!(c, '.' | '!' | '?') && at_break {
+            ends.push(i + c.len_utf8());
+        }
+    }
+    ends[1..].reverse();
+
+    let mut full_length = None;
+    for end in ends {
+        let slug = slug_body(&string[..end], &unlimited, None);
+        let untruncated = *full_length.get_or_insert(slug.len());
+        if !slug.is_empty() && slug.len() <= max_length {
+            if audit.is_some() {
+                // record what happened to the sentences that were kept
+                slug_body(&string[..end], &unlimited, audit.as_deref_mut());
+                if slug.len() < untruncated {
+                    Audit::record(&mut audit, || Redaction::Truncated {
+                        from: untruncated,
+                        to: slug.len(),
+                    });
+                }
+            }
+            return slug;
+        }
     }
 
-    let mut s = String::from_utf8(slug).unwrap();
+    let mut word_boundary = unlimited;
+    word_boundary.max_length = Some(max_length);
+    word_boundary.truncation = TruncationStrategy::WordBoundary;
+    slug_body(string, &word_boundary, audit)
+}
+
+/// Adds the random component and applies the URL encoding to a slug body.
+fn finish(s: String, config: &SlugConfig, audit: Option<&mut Audit>) -> String {
+    let s = add_randomness(s, config, audit);
+    if config.url_encode {
+        percent_encode(&s)
+    } else {
+        s
+    }
+}
 
-    match max_length {
-        Some(x) => {
-            s.truncate(x);
-            s = s.trim_end_matches(char_vec[0]).to_string();
+/// Percent-encodes every byte of `s` that is not a URL unreserved character.
+fn percent_encode(s: &str) -> String {
+    let mut encoded = String::with_capacity(s.len());
+    for b in s.bytes() {
+        if b.is_ascii_alphanumeric() || matches!(b, b'-' | b'.' | b'_' | b'~') {
+            encoded.push(b as char);
+        } else {
+            encoded.push_str(&format!("%{:02X}", b));
         }
-        None => {}
     }
+    encoded
+}
 
-    // if randomness is true, generate a nanoid with of size 5 and append it to s
-    if randomness {
-        // Decrease one from randomness_length
-        let randomness_length = randomness_length - 1;
-        let nanoid = nanoid::nanoid!(randomness_length);
+fn add_randomness(mut s: String, config: &SlugConfig, audit: Option<&mut Audit>) -> String {
+    // if randomness is true, generate a nanoid of size randomness_length and append it to s
+    if config.randomness && config.randomness_length > 0 {
+        let nanoid = random_id(config);
         // change letters to lowercase
-        let nanoid = nanoid.to_lowercase();
-        // append separator to infront of nanoid
-        s.push_str(&sep);
-        s.push_str(&nanoid);
+        let mut nanoid = nanoid.to_lowercase();
+        if let Some(audit) = audit {
+            audit.random_component = Some(nanoid.clone());
+        }
+        if s.is_empty() {
+            // there is nothing to separate the nanoid from
+            s = nanoid;
+        } else if config.randomness_prefix {
+            // put the nanoid in front, followed by the separator
+            nanoid.push_str(&config.separator);
+            s.insert_str(0, &nanoid);
+        } else {
+            // append separator to infront of nanoid
+            s.push_str(&config.separator);
+            s.push_str(&nanoid);
+        }
     }
     s
 }
 
+/// Generates the random component, seeded when a `seed` is configured.
+fn random_id(config: &SlugConfig) -> String {
+    let randomness_length = config.randomness_length;
+    #[cfg(feature = "deterministic")]
+    if let Some(seed) = config.seed {
+        let mut state = seed;
+        let alphabet = &nanoid::alphabet::SAFE;
+        return (0..randomness_length)
+            .map(|_| {
+                let bits = splitmix64(&mut state) >> 32;
+                alphabet[((bits * alphabet.len() as u64) >> 32) as usize]
+            })
+            .collect();
+    }
+    nanoid::nanoid!(randomness_length)
+}
+
+/// The SplitMix64 generator. Its algorithm is fixed, unlike the generators of `rand`, so a
+/// seeded slug stays the same across releases.
+#[cfg(feature = "deterministic")]
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Lowercases `s`, keeping runs of two or more uppercase ASCII letters as they are when
+/// `preserve_acronyms` is set.
+///
+/// `s` is the output of `deunicode`, which is always ASCII, so ASCII lowercasing is enough.
+fn to_lowercase(s: &str, preserve_acronyms: bool) -> String {
+    if !preserve_acronyms {
+        return s.to_ascii_lowercase();
+    }
+
+    let chars: Vec<char> = s.chars().collect();
+    let mut lowercase = String::with_capacity(s.len());
+    let mut i = 0;
+    while i < chars.len() {
+        let run = chars[i..]
+            .iter()
+            .take_while(|c| c.is_ascii_uppercase())
+            .count();
+        if run >= 2 {
+            lowercase.extend(&chars[i..i + run]);
+            i += run;
+        } else {
+            lowercase.push(chars[i].to_ascii_lowercase());
+            i += 1;
+        }
+    }
+    lowercase
+}
+
+/// Replaces the matches of the lowercase `word` in `s`, ignoring ASCII case, with `with`, or
+/// returns `None` if there are none.
+fn replace_ignoring_ascii_case(s: &str, word: &str, with: &str) -> Option<String> {
+    // ASCII lowercasing keeps every byte where it is, so the matches are at the same positions
+    let lowercase = s.to_ascii_lowercase();
+    let mut replaced = String::with_capacity(s.len());
+    let mut end = 0;
+    for (start, _) in lowercase.match_indices(word) {
+        replaced.push_str(&s[end..start]);
+        replaced.push_str(with);
+        end = start + word.len();
+    }
+    if end == 0 {
+        return None;
+    }
+    replaced.push_str(&s[end..]);
+    Some(replaced)
+}
+
+/// Transliterates `s` with `deunicode`, keeping the non-ASCII characters of `allow_chars`.
+fn transliterate(s: &str, allow_chars: &[char]) -> String {
+    let is_kept = |c: char| !c.is_ascii() && allow_chars.contains(&c);
+    if !s.contains(is_kept) {
+        return deunicode(s);
+    }
+
+    let mut out = String::with_capacity(s.len());
+    let mut start = 0;
+    for (i, c) in s.char_indices() {
+        if is_kept(c) {
+            let part = &s[start..i];
+            out.push_str(&deunicode(part));
+            // deunicode drops the space ending a whole word at the end of its input
+            if part
+                .chars()
+                .next_back()
+                .map_or(false, transliterates_to_word)
+            {
+                out.push(' ');
+            }
+            out.push(c);
+            start = i + c.len_utf8();
+        }
+    }
+    out.push_str(&deunicode(&s[start..]));
+    out
+}
+
+/// Whether `c` transliterates to a whole word, which deunicode marks with a trailing space.
+fn transliterates_to_word(c: char) -> bool {
+    !c.is_ascii() && deunicode_char(c).map_or(false, |t| t.ends_with(' '))
+}
+
+/// Unicode bidirectional control characters (marks, embeddings, overrides and isolates).
+fn is_bidi_control(c: char) -> bool {
+    matches!(
+        c,
+        '\u{061C}' | '\u{200E}' | '\u{200F}' | '\u{202A}'..='\u{202E}' | '\u{2066}'..='\u{2069}'
+    )
+}
+
+/// Cyrillic hard and soft signs, which only change the pronunciation of the preceding letter.
+fn is_cyrillic_sign(c: char) -> bool {
+    matches!(c, 'ъ' | 'Ъ' | 'ь' | 'Ь')
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::slugify;
+    use crate::{
+        slugify, slugify_opt, slugify_or_default, slugify_redact, slugify_to_string,
+        try_slugify_with_config, Redaction, SlugConfig, SlugError, TruncationStrategy,
+    };
+    use std::collections::HashMap;
     #[test]
     fn basic() {
         assert_eq!(
@@ -303,7 +1090,7 @@ mod tests {
         );
         assert_eq!(
             slugify("hello world ", "", "-", None, true, 5).len(),
-            "hello-world".len() + 5
+            "hello-world".len() + 1 + 5
         );
         assert_eq!(
             slugify("hello world ", "", "", None, false, 5),
@@ -311,6 +1098,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_output_is_ascii() {
+        let inputs = [
+            "Ünïcödé Straße",
+            "İstanbul ǅemal",
+            "影師嗎 Компьютер",
+            "ΑΒΓ ﬁ ﬀ",
+            "NASA's HTML Ⅻ",
+        ];
+        for input in inputs.iter() {
+            assert!(slugify!(input).is_ascii(), "{}", input);
+            assert!(slugify!(input, preserve_acronyms = true).is_ascii());
+            assert!(slugify!(input, stop_words = "a,the").is_ascii());
+            assert!(slugify!(input, max_length = 7).is_ascii());
+            assert!(slugify!(input, randomness = true).is_ascii());
+            assert!(slugify!(input, allow_chars = ['.', '_']).is_ascii());
+            assert!(slugify(input, "", "_", None, false, 5).is_ascii());
+        }
+    }
+
     #[test]
     fn test_email() {
         assert_eq!(slugify!("alice@bob.com"), "alice-bob-com");
@@ -322,10 +1129,68 @@ mod tests {
     fn test_randomness() {
         assert_eq!(
             slugify!("hello world", randomness = true).len(),
-            "hello-world".len() + 5
+            "hello-world".len() + 1 + 5
+        );
+    }
+
+    #[test]
+    fn test_short_randomness_length() {
+        assert_eq!(
+            slugify!("hello", randomness = true, randomness_length = 1).len(),
+            "hello".len() + 1 + 1
+        );
+        assert_eq!(
+            slugify!("hello", randomness = true, randomness_length = 0),
+            "hello"
+        );
+        assert_eq!(
+            slugify!("hello", randomness = true, randomness_length = 2).len(),
+            "hello".len() + 1 + 2
+        );
+    }
+
+    #[test]
+    fn test_randomness_prefix() {
+        let slug = slugify!("hello world", randomness = true, randomness_prefix = true);
+        assert_eq!(slug.len(), 5 + 1 + "hello-world".len());
+        assert!(slug.ends_with("-hello-world"));
+        assert_eq!(&slug[5..6], "-");
+
+        let slug = slugify!(
+            "hello world",
+            separator = "_",
+            randomness = true,
+            randomness_length = 8,
+            randomness_prefix = true
+        );
+        assert!(slug.ends_with("_hello_world"));
+        assert_eq!(slug.len(), 8 + 1 + "hello_world".len());
+
+        assert_eq!(
+            slugify!("hello world", randomness_prefix = true),
+            "hello-world"
         );
     }
 
+    #[test]
+    fn test_randomness_with_empty_slug() {
+        let empty = "";
+        for prefix in [false, true] {
+            let slug = slugify!(empty, randomness = true, randomness_prefix = prefix);
+            // the nanoid alone, without a separator
+            assert_eq!(slug.len(), 5);
+
+            let slug = slugify!(
+                "the a",
+                stop_words = "the,a",
+                separator = "_",
+                randomness = true,
+                randomness_prefix = prefix
+            );
+            assert_eq!(slug.len(), 5);
+        }
+    }
+
     #[test]
     fn test_starts_with_number() {
         assert_eq!(slugify!("10 amazing secrets"), "10-amazing-secrets");
@@ -336,7 +1201,7 @@ mod tests {
         assert_eq!(slugify!("the 101 dalmatians"), "the-101-dalmatians");
         assert_eq!(
             slugify!("the 101 dalmatians", randomness = true).len(),
-            "the-101-dalmatians".len() + 5
+            "the-101-dalmatians".len() + 1 + 5
         );
     }
 
@@ -346,17 +1211,47 @@ mod tests {
     }
 
     #[test]
-    fn test_numbers_only() {
-        assert_eq!(slugify!("101"), "101");
-    }
-
-    #[test]
-    fn test_numbers_and_symbols() {
+    fn test_strip_leading_digits() {
         assert_eq!(
-            slugify!("1000 reasons you are #1"),
-            "1000-reasons-you-are-1"
+            slugify!("10 tips for rust", strip_leading_digits = true),
+            "tips-for-rust"
         );
-    }
+        assert_eq!(
+            slugify!("2024 10 best crates", strip_leading_digits = true),
+            "best-crates"
+        );
+        assert_eq!(
+            slugify!("3d printing", strip_leading_digits = true),
+            "d-printing"
+        );
+        assert_eq!(
+            slugify!("rust 2024", strip_leading_digits = true),
+            "rust-2024"
+        );
+        assert_eq!(slugify!("101", strip_leading_digits = true), "");
+        // the length is measured after stripping
+        assert_eq!(
+            slugify!(
+                "10 tips for rust",
+                strip_leading_digits = true,
+                max_length = 8
+            ),
+            "tips-for"
+        );
+    }
+
+    #[test]
+    fn test_numbers_only() {
+        assert_eq!(slugify!("101"), "101");
+    }
+
+    #[test]
+    fn test_numbers_and_symbols() {
+        assert_eq!(
+            slugify!("1000 reasons you are #1"),
+            "1000-reasons-you-are-1"
+        );
+    }
 
     #[test]
     fn test_stop_words() {
@@ -367,10 +1262,42 @@ mod tests {
         assert_eq!(slugify!("hello world", stop_words = "world"), "hello");
         assert_eq!(
             slugify!("hello world", stop_words = "world", randomness = true).len(),
-            "hello".len() + 5
+            "hello".len() + 1 + 5
         );
     }
 
+    #[test]
+    fn test_stop_words_at_edges() {
+        assert_eq!(
+            slugify!(" the quick brown fox ", stop_words = "the"),
+            "quick-brown-fox"
+        );
+        assert_eq!(
+            slugify!(" the quick brown fox ", stop_words = "the,fox"),
+            "quick-brown"
+        );
+        assert_eq!(
+            slugify!(
+                " the quick brown fox ",
+                stop_words = "the,fox",
+                separator = " "
+            ),
+            "quick brown"
+        );
+        assert_eq!(
+            slugify!("the fox", stop_words = "the,fox", separator = "_"),
+            ""
+        );
+    }
+
+    #[test]
+    fn test_config_stop_words() {
+        let mut config = SlugConfig::default();
+        crate::__slugify_option!(config, stop_words, "the,big,,old");
+        assert_eq!(config.stop_words, ["the", "big", "old"]);
+        assert_eq!(config.generate("the big red dog"), "red-dog");
+    }
+
     #[test]
     fn test_differently_cased_stopword_match() {
         assert_eq!(
@@ -436,7 +1363,7 @@ mod tests {
                 8
             )
             .len(),
-            "quick brown fox jumps over lazy dog".len() + 8
+            "quick brown fox jumps over lazy dog".len() + 1 + 8
         );
         assert_eq!(
             slugify!(
@@ -455,7 +1382,7 @@ mod tests {
                 randomness = true
             )
             .len(),
-            "quick brown fox jumps over lazy dog".len() + 5
+            "quick brown fox jumps over lazy dog".len() + 1 + 5
         );
 
         assert_eq!(
@@ -467,8 +1394,19 @@ mod tests {
                 randomness_length = 10
             )
             .len(),
-            "quick brown fox jumps over lazy dog".len() + 10
+            "quick brown fox jumps over lazy dog".len() + 1 + 10
+        );
+    }
+
+    #[test]
+    fn test_input_max_length() {
+        assert_eq!(slugify!("hello world", input_max_length = 8), "hello-wo");
+        assert_eq!(
+            slugify!("hello world", input_max_length = 100),
+            "hello-world"
         );
+        // the input is cut on a char boundary, not a byte boundary
+        assert_eq!(slugify!("影師嗎 hello", input_max_length = 2), "ying-shi");
     }
 
     #[test]
@@ -484,7 +1422,657 @@ mod tests {
         assert_eq!(slugify!("hello world", separator = "_"), "hello_world");
         assert_eq!(
             slugify!("hello world-", separator = "_", randomness = true).len(),
-            "hello_world".len() + 5
+            "hello_world".len() + 1 + 5
+        );
+    }
+
+    #[test]
+    fn test_runtime_separator() {
+        let use_underscore = true;
+        let sep = if use_underscore { "_" } else { "-" };
+        assert_eq!(slugify!("hello world", separator = sep), "hello_world");
+        assert_eq!(
+            slugify!("the hello world", stop_words = "the", separator = sep),
+            "hello_world"
+        );
+        assert_eq!(
+            slugify!("hello world", separator = sep, max_length = 7),
+            "hello_w"
+        );
+        assert_eq!(
+            slugify!("hello world", separator = sep, randomness = true).len(),
+            "hello_world".len() + 1 + 5
+        );
+
+        let owned = String::from(".");
+        assert_eq!(slugify!("hello world", separator = &owned), "hello.world");
+    }
+
+    #[test]
+    fn test_preserve_separator() {
+        assert_eq!(
+            slugify!("hello-world", separator = "_", preserve_separator = true),
+            "hello_world"
+        );
+        assert_eq!(
+            slugify!("hello--world", separator = "_", preserve_separator = true),
+            "hello__world"
+        );
+        // whitespace and punctuation next to a hyphen run into its separator
+        assert_eq!(
+            slugify!("hello - world", separator = "_", preserve_separator = true),
+            "hello_world"
+        );
+        assert_eq!(
+            slugify!("a - - b, c", separator = "_", preserve_separator = true),
+            "a__b_c"
+        );
+        assert_eq!(slugify!("--hello--", preserve_separator = true), "hello");
+        assert_eq!(
+            slugify!("a--b", preserve_separator = true, preserve_hyphens = true),
+            "a-b"
+        );
+        assert_eq!(
+            slugify!("a--b--c", preserve_separator = true, max_length = 4),
+            "a--b"
+        );
+    }
+
+    #[test]
+    fn test_preserve_hyphens() {
+        assert_eq!(
+            slugify!("Coca-Cola Zero", separator = "_", preserve_hyphens = true),
+            "coca-cola_zero"
+        );
+        assert_eq!(
+            slugify!(
+                "v1-0-stable build",
+                separator = ".",
+                preserve_hyphens = true
+            ),
+            "v1-0-stable.build"
+        );
+        assert_eq!(
+            slugify!("-well--known-", separator = "_", preserve_hyphens = true),
+            "well-known"
+        );
+        assert_eq!(
+            slugify!(
+                "well-known fact",
+                separator = "_",
+                preserve_hyphens = true,
+                max_length = 11
+            ),
+            "well-known"
+        );
+        assert_eq!(
+            slugify!("well-known", separator = "_", preserve_hyphens = false),
+            "well_known"
+        );
+    }
+
+    #[test]
+    fn test_macro_option_order() {
+        assert_eq!(
+            slugify!(
+                "the hello world",
+                max_length = 20,
+                separator = "_",
+                stop_words = "the"
+            ),
+            slugify!(
+                "the hello world",
+                stop_words = "the",
+                separator = "_",
+                max_length = 20
+            )
+        );
+        assert_eq!(
+            slugify!("hello world", randomness_length = 8, randomness = true).len(),
+            "hello-world".len() + 1 + 8
+        );
+    }
+
+    #[test]
+    fn test_owned_string_input() {
+        let title = String::from("Hello World");
+        assert_eq!(slugify!(title.clone()), "hello-world");
+        assert_eq!(slugify!(&title, separator = "_"), "hello_world");
+        assert_eq!(slugify(title, "", "-", None, false, 5), "hello-world");
+    }
+
+    #[test]
+    fn test_preserve_acronyms() {
+        assert_eq!(
+            slugify!("NASA's new HTML parser", preserve_acronyms = true),
+            "NASA-s-new-HTML-parser"
+        );
+        assert_eq!(slugify!("NASA's new HTML parser"), "nasa-s-new-html-parser");
+        assert_eq!(
+            slugify!("A Quick Test", preserve_acronyms = true),
+            "a-quick-test"
+        );
+        assert_eq!(
+            slugify!("Über HTTP", preserve_acronyms = true, separator = "_"),
+            "uber_HTTP"
+        );
+    }
+
+    #[test]
+    fn test_stop_words_with_preserve_acronyms() {
+        assert_eq!(
+            slugify!(
+                "NASA launches",
+                stop_words = "nasa",
+                preserve_acronyms = true
+            ),
+            "launches"
+        );
+        assert_eq!(
+            slugify!(
+                "The NASA and ESA launch",
+                stop_words = "the,and",
+                preserve_acronyms = true
+            ),
+            "NASA-ESA-launch"
+        );
+        assert_eq!(
+            slugify!(
+                "HTML and CSS",
+                stop_words = ["CSS"],
+                preserve_acronyms = true
+            ),
+            "HTML-and"
+        );
+    }
+
+    #[test]
+    fn test_allow_chars() {
+        assert_eq!(slugify!("1.0.2", allow_chars = ['.']), "1.0.2");
+        assert_eq!(slugify!("1.0.2"), "1-0-2");
+        assert_eq!(
+            slugify!("well-known fact", separator = "_", allow_chars = ['-']),
+            "well-known_fact"
+        );
+        let allowed: &[char] = &['.', '+'];
+        assert_eq!(slugify!("C++ 2.0", allow_chars = allowed), "c++-2.0");
+    }
+
+    #[test]
+    fn test_allow_non_ascii_chars() {
+        assert_eq!(
+            slugify!("Café au lait", allow_chars = ['é']),
+            "café-au-lait"
+        );
+        assert_eq!(
+            slugify!("Straße Über", allow_chars = ['ß', 'ü']),
+            "straße-uber"
+        );
+        // only the listed characters are kept, other ones are still transliterated
+        assert_eq!(slugify!("CAFÉ crème", allow_chars = ['é']), "cafe-creme");
+        assert_eq!(slugify!("東京", allow_chars = ['京']), "dong-京");
+        assert_eq!(
+            slugify!("Café au lait", allow_chars = ['é'], url_encode = true),
+            "caf%C3%A9-au-lait"
+        );
+    }
+
+    #[test]
+    fn test_min_length() {
+        assert_eq!(slugify!("hi", min_length = 5, max_length = 20), "hi000");
+        assert_eq!(slugify!("hello world", min_length = 5), "hello-world");
+        let empty = "";
+        assert_eq!(slugify!(empty, min_length = 3), "000");
+        // padding is capped at max_length
+        assert_eq!(
+            slugify!("hello world", min_length = 20, max_length = 5),
+            "hello"
+        );
+        assert_eq!(slugify!("hi", min_length = 20, max_length = 5), "hi000");
+        assert_eq!(
+            slugify!("hi", min_length = 5, max_length = 5, randomness = true).len(),
+            "hi000".len() + 1 + 5
+        );
+        // the fallback is used for empty slugs, and padded like any other slug
+        assert_eq!(
+            slugify!(
+                "the a",
+                stop_words = "the,a",
+                min_length = 3,
+                empty_fallback = "x"
+            ),
+            "x00"
+        );
+        assert_eq!(
+            slugify!(
+                "the",
+                stop_words = "the",
+                min_length = 3,
+                empty_fallback = "untitled"
+            ),
+            "untitled"
+        );
+        let config = SlugConfig {
+            stop_words: vec![String::from("the")],
+            min_length: Some(3),
+            ..SlugConfig::default()
+        };
+        assert_eq!(
+            try_slugify_with_config("the", &config),
+            Err(SlugError::EmptyResult)
+        );
+        assert_eq!(
+            try_slugify_with_config("the hi", &config),
+            Ok(String::from("hi0"))
+        );
+        assert_eq!(
+            slugify!("hi", min_length = 5, randomness = true).len(),
+            "hi000".len() + 1 + 5
+        );
+    }
+
+    #[test]
+    fn test_empty_result() {
+        assert_eq!(slugify!("   "), "");
+        assert_eq!(slugify!("---", empty_fallback = "untitled"), "untitled");
+        assert_eq!(slugify!("hello", empty_fallback = "untitled"), "hello");
+        assert_eq!(
+            slugify!("???", empty_fallback = "untitled", randomness = true).len(),
+            "untitled".len() + 1 + 5
+        );
+
+        let config = SlugConfig::default();
+        assert_eq!(
+            try_slugify_with_config("   ", &config),
+            Err(SlugError::EmptyResult)
+        );
+        assert_eq!(
+            try_slugify_with_config("hello world", &config),
+            Ok(String::from("hello-world"))
+        );
+        let config = SlugConfig {
+            empty_fallback: Some(String::from("untitled")),
+            ..SlugConfig::default()
+        };
+        assert_eq!(
+            try_slugify_with_config("   ", &config),
+            Ok(String::from("untitled"))
+        );
+    }
+
+    #[test]
+    fn test_slugify_or_default() {
+        assert_eq!(slugify_or_default("Hello World", "untitled"), "hello-world");
+        assert_eq!(slugify_or_default("   ", "untitled"), "untitled");
+        // the default is not slugified
+        assert_eq!(slugify_or_default("", "Untitled Post"), "Untitled Post");
+    }
+
+    #[test]
+    fn test_slugify_opt() {
+        let config = SlugConfig::default();
+        assert_eq!(slugify_opt(Some("Hello World"), &config), "hello-world");
+        assert_eq!(
+            slugify_opt(Some(String::from("Hello World")), &config),
+            "hello-world"
+        );
+        let title: Option<String> = None;
+        assert_eq!(slugify_opt(title.as_ref(), &config), "");
+        assert_eq!(slugify_opt(title, &config), "");
+
+        let config = SlugConfig {
+            empty_fallback: Some(String::from("untitled")),
+            ..SlugConfig::default()
+        };
+        assert_eq!(slugify_opt(None::<&str>, &config), "untitled");
+    }
+
+    #[test]
+    fn test_slugify_to_string() {
+        let config = SlugConfig {
+            separator: String::from("_"),
+            ..SlugConfig::default()
+        };
+        let mut buf = String::from("/posts/");
+        slugify_to_string("Hello World", &config, &mut buf);
+        assert_eq!(buf, "/posts/hello_world");
+        slugify_to_string("???", &config, &mut buf);
+        assert_eq!(buf, "/posts/hello_world");
+    }
+
+    #[test]
+    fn test_config_generate() {
+        let config = SlugConfig {
+            stop_words: vec![String::from("the")],
+            max_length: Some(10),
+            ..SlugConfig::default()
+        };
+        assert_eq!(config.generate("The Hello World"), "hello-worl");
+        assert_eq!(config.generate(String::from("the end")), "end");
+    }
+
+    #[test]
+    fn test_separator_collapsing() {
+        assert_eq!(slugify!("hello - world"), "hello-world");
+        assert_eq!(slugify!("hello  -  world"), "hello-world");
+        assert_eq!(slugify!("hello_-_world", separator = "_"), "hello_world");
+        assert_eq!(slugify!("a -- . -- b", separator = "."), "a.b");
+        assert_eq!(slugify!(" - hello world - "), "hello-world");
+        assert_eq!(slugify!("the the a the b the", stop_words = "the"), "a-b");
+        assert_eq!(
+            slugify!("a & b", transliteration_overrides = [('&', "")]),
+            "a-b"
+        );
+        assert_eq!(
+            slugify!("a & b", transliteration_overrides = [('&', " - ")]),
+            "a-b"
+        );
+    }
+
+    #[test]
+    fn test_cow_input() {
+        use std::borrow::Cow;
+
+        assert_eq!(slugify!(Cow::Borrowed("Hello World")), "hello-world");
+        let owned: Cow<str> = Cow::Owned(String::from("Hello World"));
+        assert_eq!(slugify!(&owned, separator = "_"), "hello_world");
+        assert_eq!(slugify!(owned), "hello-world");
+        assert_eq!(
+            slugify(Cow::Borrowed("Hello World"), "", "-", None, false, 5),
+            "hello-world"
+        );
+        assert_eq!(
+            SlugConfig::default().generate(String::from_utf8_lossy(b"Hello World")),
+            "hello-world"
+        );
+    }
+
+    #[test]
+    fn test_truncation_strategy() {
+        use crate::TruncationStrategy::*;
+
+        let text = "hello world foo";
+        assert_eq!(slugify!(text, max_length = 8), "hello-wo");
+        assert_eq!(
+            slugify!(text, max_length = 8, truncation = ExactChar),
+            "hello-wo"
+        );
+        assert_eq!(
+            slugify!(text, max_length = 8, truncation = WordBoundary),
+            "hello"
+        );
+        assert_eq!(
+            slugify!(text, max_length = 11, truncation = WordBoundary),
+            "hello-world"
+        );
+        assert_eq!(
+            slugify!(text, max_length = 3, truncation = WordBoundary),
+            "hel"
+        );
+        assert_eq!(
+            slugify!(text, max_length = 6, truncation = NoBoundary),
+            "hello-"
+        );
+        assert_eq!(
+            slugify!(text, max_length = 6, truncation = ExactChar),
+            "hello"
+        );
+        assert_eq!(
+            slugify!(text, max_length = 20, truncation = NoBoundary),
+            "hello-world-foo"
+        );
+    }
+
+    #[test]
+    fn test_truncation_sentence_boundary() {
+        use crate::TruncationStrategy::SentenceBoundary;
+
+        let text = "Rust 1.56 is out. It has a new edition! Upgrade today.";
+        assert_eq!(
+            slugify!(text, max_length = 40, truncation = SentenceBoundary),
+            "rust-1-56-is-out-it-has-a-new-edition"
+        );
+        assert_eq!(
+            slugify!(text, max_length = 20, truncation = SentenceBoundary),
+            "rust-1-56-is-out"
+        );
+        assert_eq!(
+            slugify!(text, max_length = 100, truncation = SentenceBoundary),
+            "rust-1-56-is-out-it-has-a-new-edition-upgrade-today"
+        );
+        // not even the first sentence fits, so the slug is cut at a word boundary
+        assert_eq!(
+            slugify!(text, max_length = 12, truncation = SentenceBoundary),
+            "rust-1-56-is"
+        );
+        assert_eq!(
+            slugify!(
+                "no sentence end here",
+                max_length = 12,
+                truncation = SentenceBoundary
+            ),
+            "no-sentence"
+        );
+    }
+
+    #[test]
+    fn test_segment_separator() {
+        assert_eq!(
+            slugify!(
+                "blog/My First Post",
+                separator = "_",
+                segment_separator = "-"
+            ),
+            "blog-my_first_post"
+        );
+        assert_eq!(
+            slugify!(
+                "shop . shoes / Running Shoes",
+                separator = "_",
+                segment_separator = "--"
+            ),
+            "shop--shoes--running_shoes"
+        );
+        assert_eq!(
+            slugify!("/a b//c d/", separator = "_", segment_separator = "/"),
+            "a_b/c_d"
+        );
+        assert_eq!(
+            slugify!("a b/c d", separator = "-", segment_separator = ""),
+            "a-bc-d"
+        );
+        assert_eq!(slugify!("a b/c d", segment_separator = "-"), "a-b-c-d");
+        assert_eq!(
+            slugify!(
+                "blog/hello world",
+                separator = "_",
+                segment_separator = "-",
+                max_length = 8
+            ),
+            "blog-hel"
+        );
+        assert_eq!(
+            slugify!(
+                "blog/hello world",
+                separator = "_",
+                segment_separator = "-",
+                max_length = 5
+            ),
+            "blog"
+        );
+        // a longer segment separator is used and counted in full
+        assert_eq!(
+            slugify!("blog/hello", segment_separator = "--", max_length = 7),
+            "blog--h"
+        );
+        assert_eq!(
+            slugify!("blog/hello", segment_separator = "--", max_length = 6),
+            "blog"
+        );
+        // without a segment separator, `.` and `/` are ordinary punctuation
+        assert_eq!(slugify!("blog/a.b", separator = "_"), "blog_a_b");
+    }
+
+    #[test]
+    fn test_literal_text() {
+        assert_eq!(slugify!(42), "42");
+        assert_eq!(slugify!(2024, min_length = 6), "202400");
+        assert_eq!(slugify!(-7), "7");
+        assert_eq!(slugify!(1.5, separator = "_"), "1_5");
+        assert_eq!(slugify!(true), "true");
+        assert_eq!(slugify!('A'), "a");
+        assert_eq!(slugify!(text = 42), "42");
+        assert_eq!(slugify!("Hello", separator = "_"), "hello");
+    }
+
+    #[test]
+    fn test_named_text() {
+        let title = "Hello World";
+        let sep = "_";
+        assert_eq!(slugify!(text = title), "hello-world");
+        assert_eq!(slugify!(text = title, separator = sep), "hello_world");
+        assert_eq!(
+            slugify!(
+                text = String::from("the end"),
+                stop_words = "the",
+                max_length = 2
+            ),
+            "en"
+        );
+
+        // a variable that happens to be called `text` still works positionally
+        let text = "positional text";
+        assert_eq!(slugify!(text), "positional-text");
+        assert_eq!(slugify!(text, separator = "."), "positional.text");
+    }
+
+    #[test]
+    fn test_send_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<SlugConfig>();
+        assert_send_sync::<SlugError>();
+
+        let config = std::sync::Arc::new(SlugConfig {
+            separator: String::from("_"),
+            ..SlugConfig::default()
+        });
+        let handles: Vec<_> = (0..4)
+            .map(|i| {
+                let config = std::sync::Arc::clone(&config);
+                std::thread::spawn(move || config.generate(format!("thread {}", i)))
+            })
+            .collect();
+        let slugs: Vec<String> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+        assert_eq!(slugs, ["thread_0", "thread_1", "thread_2", "thread_3"]);
+    }
+
+    #[test]
+    fn test_config_eq() {
+        let config = SlugConfig {
+            separator: String::from("_"),
+            ..SlugConfig::default()
+        };
+        assert_eq!(config, config.clone());
+        assert_ne!(config, SlugConfig::default());
+        assert_eq!(
+            SlugConfig {
+                separator: String::from("-"),
+                ..config
+            },
+            SlugConfig::default()
+        );
+    }
+
+    #[test]
+    fn test_slugify_iter() {
+        let titles = vec![String::from("Hello World"), String::from("the end")];
+        let slugs: Vec<String> = slugify_iter!(titles, stop_words = "the").collect();
+        assert_eq!(slugs, ["hello-world", "end"]);
+
+        let mut slugs = slugify_iter!("a b,c d".split(','), separator = "_");
+        assert_eq!(slugs.next().as_deref(), Some("a_b"));
+        assert_eq!(slugs.next().as_deref(), Some("c_d"));
+        assert_eq!(slugs.next(), None);
+    }
+
+    #[test]
+    fn test_slugify_iter_is_lazy() {
+        let mut polled = 0;
+        let mut slugs = slugify_iter!(["one", "two", "three"].iter().inspect(|_| polled += 1));
+        assert_eq!(slugs.next().as_deref(), Some("one"));
+        drop(slugs);
+        assert_eq!(polled, 1);
+    }
+
+    #[test]
+    fn test_unusual_separators() {
+        assert_eq!(slugify!("hello world", separator = "→"), "hello→world");
+        assert_eq!(slugify!("hello, world!", separator = "→"), "hello→world");
+        assert_eq!(
+            slugify!("hello world", separator = "→", max_length = 6),
+            "hello"
+        );
+        assert_eq!(
+            slugify!("hello world", separator = "→", max_length = 7),
+            "hello"
+        );
+        assert_eq!(slugify!("hello.world", separator = ""), "helloworld");
+        assert_eq!(
+            slugify!("hello.world", separator = "", max_length = 7),
+            "hellowo"
+        );
+    }
+
+    #[test]
+    fn test_trailing_comma() {
+        assert_eq!(slugify!("hello world",), "hello-world");
+        assert_eq!(slugify!("hello world", separator = "_",), "hello_world");
+        assert_eq!(
+            slugify!("the hello world", stop_words = "the", max_length = 5,),
+            "hello"
+        );
+        assert_eq!(
+            slugify!(text = "hello world", separator = "_",),
+            "hello_world"
+        );
+        assert_eq!(slugify!(2024,), "2024");
+        let text = String::from("hello world");
+        assert_eq!(slugify!(&text,), "hello-world");
+        let slugs: Vec<String> = slugify_iter!(vec!["a b", "c d"], separator = "_",).collect();
+        assert_eq!(slugs, ["a_b", "c_d"]);
+    }
+
+    #[test]
+    fn test_alphanumeric_separator() {
+        assert_eq!(slugify!("pizza world", separator = "a"), "pizzaaworld");
+        assert_eq!(slugify!("area 51", separator = "a"), "areaa51");
+        assert_eq!(slugify!("a b", separator = "x"), "axb");
+        assert_eq!(slugify!("x, y", separator = "x"), "xxy");
+        assert_eq!(
+            slugify!("hello world", separator = "x", max_length = 6),
+            "hello"
+        );
+        assert_eq!(
+            slugify!(
+                "hello world",
+                separator = "o",
+                max_length = 8,
+                truncation = TruncationStrategy::WordBoundary
+            ),
+            "hello"
+        );
+        assert_eq!(
+            slugify!("42 things", separator = "1", strip_leading_digits = true),
+            "things"
+        );
+        assert_eq!(
+            slugify!("the a team", stop_words = "the", separator = "a"),
+            "aateam"
+        );
+        assert_eq!(slugify!("hello, world", separator = "ab"), "helloabworld");
+        assert_eq!(
+            slugify!("hello world", separator = "--", max_length = 6),
+            "hello"
         );
     }
 
@@ -493,6 +2081,23 @@ mod tests {
         assert_eq!(slugify("影師嗎", "", "-", None, false, 5), "ying-shi-ma");
     }
 
+    #[test]
+    fn test_mixed_cjk_and_ascii() {
+        assert_eq!(slugify!("hello世界rust"), "hello-shi-jie-rust");
+        assert_eq!(slugify!("ab影cd"), "ab-ying-cd");
+        assert_eq!(slugify!("影師嗎abc"), "ying-shi-ma-abc");
+        assert_eq!(slugify!("hello世界"), "hello-shi-jie");
+        assert_eq!(slugify!("世界hello"), "shi-jie-hello");
+        assert_eq!(slugify!("rust2世界"), "rust2-shi-jie");
+        assert_eq!(slugify!("rust🦀"), "rust-crab");
+        assert_eq!(
+            slugify!("hello世界rust", separator = "_"),
+            "hello_shi_jie_rust"
+        );
+        // accented latin letters still transliterate within the word
+        assert_eq!(slugify!("café"), "cafe");
+    }
+
     #[test]
     fn test_accented_text() {
         assert_eq!(slugify("Æúű--cool?", "", "-", None, false, 5), "aeuu-cool");
@@ -507,14 +2112,164 @@ mod tests {
         assert_eq!(slugify!("jaja---lol-méméméoo--a"), "jaja-lol-mememeoo-a");
     }
 
+    #[test]
+    fn test_bidi_controls() {
+        assert_eq!(
+            slugify!("\u{200F}hello\u{202B} world\u{202C}"),
+            "hello-world"
+        );
+        assert_eq!(slugify!("\u{2067}שלום\u{2069} עולם"), "shlvm-vlm");
+        assert_eq!(slugify!("\u{200E}\u{200F}"), "");
+
+        // stripped before allow_chars is looked at, unless turned off
+        assert_eq!(
+            slugify!("hello\u{200F} world", allow_chars = ['\u{200F}']),
+            "hello-world"
+        );
+        assert_eq!(
+            slugify!(
+                "hello\u{200F} world",
+                allow_chars = ['\u{200F}'],
+                strip_bidi_controls = false
+            ),
+            "hello\u{200F}-world"
+        );
+        assert_eq!(
+            slugify!("\u{200F}hello\u{202B} world", strip_bidi_controls = false),
+            "hello-world"
+        );
+        let redactions = slugify_redact("a\u{200F}b", &SlugConfig::default()).1;
+        assert_eq!(redactions, [Redaction::CharStripped('\u{200F}')]);
+        let config = SlugConfig {
+            strip_bidi_controls: false,
+            ..SlugConfig::default()
+        };
+        assert!(!slugify_redact("a\u{200F}b", &config)
+            .1
+            .contains(&Redaction::CharStripped('\u{200F}')));
+    }
+
     #[test]
     fn test_cyrillic_text() {
-        assert_eq!(slugify!("Компьютер"), "komp-iuter");
+        assert_eq!(slugify!("Компьютер"), "kompiuter");
+        assert_eq!(slugify!("Подъезд и мальчик"), "podezd-i-malchik");
+        assert_eq!(slugify!("ОБЪЕКТ"), "obekt");
+        assert_eq!(slugify!("ь ъ"), "");
+    }
+
+    #[cfg(not(feature = "numbers-to-words"))]
+    #[test]
+    fn test_numbers_to_words_without_feature() {
+        assert_eq!(
+            slugify!("2 fast 2 furious", numbers_to_words = true),
+            "2-fast-2-furious"
+        );
+    }
+
+    #[cfg(not(feature = "deterministic"))]
+    #[test]
+    fn test_seed_without_feature() {
+        let slug = slugify!("hello world", randomness = true, seed = 42);
+        assert!(slug.starts_with("hello-world-"));
+        assert_eq!(slug.len(), "hello-world".len() + 1 + 5);
+        assert_eq!(slugify!("hello world", seed = 42), "hello-world");
+    }
+
+    #[cfg(feature = "deterministic")]
+    #[test]
+    fn test_seed() {
+        let slug = slugify!("hello world", randomness = true, seed = 42);
+        assert_eq!(slug, slugify!("hello world", randomness = true, seed = 42));
+        assert_ne!(slug, slugify!("hello world", randomness = true, seed = 43));
+        assert!(slug.starts_with("hello-world-"));
+        assert_eq!(slug.len(), "hello-world".len() + 1 + 5);
+        assert_eq!(
+            slugify!("hello", randomness = true, randomness_length = 8, seed = 7).len(),
+            "hello".len() + 1 + 8
+        );
+        // the seed only matters with randomness
+        assert_eq!(slugify!("hello world", seed = 42), "hello-world");
+        // SplitMix64 output for seed 42, which must never change
+        assert_eq!(slug, "hello-world-j8fk0");
+    }
+
+    #[test]
+    fn test_url_encode() {
+        assert_eq!(slugify!("hello world", url_encode = true), "hello-world");
+        assert_eq!(
+            slugify!("hello world", separator = "→", url_encode = true),
+            "hello%E2%86%92world"
+        );
+        assert_eq!(
+            slugify!("hello world", separator = " ", url_encode = true),
+            "hello%20world"
+        );
+        assert_eq!(
+            slugify!("???", empty_fallback = "café", url_encode = true),
+            "caf%C3%A9"
+        );
+        assert_eq!(
+            slugify!("C++ 2.0", allow_chars = ['+', '.'], url_encode = true),
+            "c%2B%2B-2.0"
+        );
+        assert_eq!(
+            slugify!(
+                "???",
+                empty_fallback = "a b",
+                separator = "_",
+                url_encode = true
+            ),
+            "a%20b"
+        );
+    }
+
+    #[test]
+    fn test_deunicode_fallback() {
+        assert_eq!(slugify!("ab\u{E123}cd"), "ab-cd");
+        assert_eq!(slugify!("ab\u{E123}cd", deunicode_fallback = ""), "abcd");
+        assert_eq!(slugify!("ab\u{E123}cd", deunicode_fallback = "x"), "abxcd");
+        assert_eq!(
+            slugify!("\u{E123} logo", deunicode_fallback = "unknown"),
+            "unknown-logo"
+        );
+        // characters with a transliteration are not affected
+        assert_eq!(slugify!("café", deunicode_fallback = "x"), "cafe");
+        assert_eq!(slugify!("a [?] b", deunicode_fallback = "x"), "a-b");
+    }
+
+    #[test]
+    fn test_transliteration_overrides() {
+        assert_eq!(
+            slugify!(
+                "Grüße aus Köln",
+                transliteration_overrides = [('ü', "ue"), ('ö', "oe")]
+            ),
+            "gruesse-aus-koeln"
+        );
+        assert_eq!(
+            slugify!("Über", transliteration_overrides = [('ü', "ue")]),
+            "uber"
+        );
+        assert_eq!(
+            slugify!("Tom & Jerry", transliteration_overrides = [('&', " and ")]),
+            "tom-and-jerry"
+        );
+        assert_eq!(
+            slugify!("Компьютер", transliteration_overrides = [('ь', "y")]),
+            "kompyiuter"
+        );
+
+        let mut overrides = HashMap::new();
+        overrides.insert('ß', String::from("sz"));
+        assert_eq!(
+            slugify!("Straße", transliteration_overrides = overrides),
+            "strasze"
+        );
     }
 
     #[test]
     fn test_macro() {
-        assert_eq!(slugify!("Компьютер"), "komp-iuter");
+        assert_eq!(slugify!("Компьютер"), "kompiuter");
         assert_eq!(slugify!("hello world", separator = "-"), "hello-world");
         assert_eq!(slugify!("hello world", separator = " "), "hello world");
         assert_eq!(slugify!("hello world", max_length = 5), "hello");
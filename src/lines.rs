@@ -0,0 +1,44 @@
+use crate::{slugify_with_config, SlugConfig};
+
+/// Slugifies every line of `text` with `config`.
+///
+/// Both `\n` and `\r\n` line endings are handled. Blank lines, and lines that produce an empty
+/// slug, are skipped.
+///
+/// ```rust
+/// # use slugify_rs::{slugify_lines, SlugConfig};
+/// let text = "Hello World\r\n\r\nAnother Title\n";
+/// assert_eq!(slugify_lines(text, &SlugConfig::default()), ["hello-world", "another-title"]);
+/// ```
+pub fn slugify_lines(text: &str, config: &SlugConfig) -> Vec<String> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| slugify_with_config(line, config))
+        .filter(|slug| !slug.is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{slugify_lines, SlugConfig};
+
+    #[test]
+    fn test_slugify_lines() {
+        let text = "  First Title  \n\n   \n!!!\nSecond Title\r\n\r\n-- ? --\r\nLast";
+        assert_eq!(
+            slugify_lines(text, &SlugConfig::default()),
+            ["first-title", "second-title", "last"]
+        );
+
+        let config = SlugConfig {
+            empty_fallback: Some(String::from("untitled")),
+            ..SlugConfig::default()
+        };
+        assert_eq!(
+            slugify_lines("One\n!!!\nTwo", &config),
+            ["one", "untitled", "two"]
+        );
+        assert!(slugify_lines("", &config).is_empty());
+    }
+}
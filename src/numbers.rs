@@ -0,0 +1,152 @@
+const ONES: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+const TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+/// English words for `n`, which must be below 1000.
+fn number_to_words(n: usize) -> String {
+    match n {
+        0..=19 => ONES[n].to_string(),
+        20..=99 if n % 10 == 0 => TENS[n / 10].to_string(),
+        20..=99 => format!("{} {}", TENS[n / 10], ONES[n % 10]),
+        _ if n % 100 == 0 => format!("{} hundred", ONES[n / 100]),
+        _ => format!("{} hundred {}", ONES[n / 100], number_to_words(n % 100)),
+    }
+}
+
+/// Replaces the numbers from 0 to 999 in `s` with their English words.
+///
+/// Digits joined by `,`, `.` or `-`, like `1,000`, `3.14` or `2024-01-15`, are a single number,
+/// which is only converted if it is a plain integer in range. Larger numbers, numbers with
+/// leading zeros and digits that are part of a word like `2nd` or `mp3` are left as they are.
+pub(crate) fn numbers_to_words(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+
+    while let Some(start) = rest.find(|c: char| c.is_ascii_digit()) {
+        let number_len = number_len(&rest[start..]);
+        let number = &rest[start..start + number_len];
+        let before = rest[..start].chars().next_back();
+        let after = rest[start + number_len..].chars().next();
+
+        out.push_str(&rest[..start]);
+        let in_word = before.map_or(false, |c| c.is_alphabetic())
+            || after.map_or(false, |c| c.is_alphabetic());
+        let leading_zero = number.len() > 1 && number.starts_with('0');
+        match number.parse::<usize>() {
+            Ok(n) if n < 1000 && !in_word && !leading_zero => {
+                out.push(' ');
+                out.push_str(&number_to_words(n));
+                out.push(' ');
+            }
+            _ => out.push_str(number),
+        }
+        rest = &rest[start + number_len..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Length of the number at the start of `s`: a run of digits, followed by more runs of digits
+/// each joined to the previous one by a single `,`, `.` or `-`.
+fn number_len(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    let mut len = 0;
+    loop {
+        while len < bytes.len() && bytes[len].is_ascii_digit() {
+            len += 1;
+        }
+        let joined = len + 1 < bytes.len()
+            && matches!(bytes[len], b',' | b'.' | b'-')
+            && bytes[len + 1].is_ascii_digit();
+        if !joined {
+            return len;
+        }
+        len += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{number_to_words, numbers_to_words};
+    use crate::slugify;
+
+    #[test]
+    fn test_number_to_words() {
+        assert_eq!(number_to_words(0), "zero");
+        assert_eq!(number_to_words(13), "thirteen");
+        assert_eq!(number_to_words(40), "forty");
+        assert_eq!(number_to_words(42), "forty two");
+        assert_eq!(number_to_words(100), "one hundred");
+        assert_eq!(number_to_words(101), "one hundred one");
+        assert_eq!(number_to_words(999), "nine hundred ninety nine");
+    }
+
+    #[test]
+    fn test_numbers_to_words() {
+        assert_eq!(numbers_to_words("2 fast"), " two  fast");
+        assert_eq!(numbers_to_words("year 2024"), "year 2024");
+        assert_eq!(numbers_to_words("the 2nd mp3"), "the 2nd mp3");
+        assert_eq!(numbers_to_words("1,000 users"), "1,000 users");
+        assert_eq!(numbers_to_words("pi is 3.14"), "pi is 3.14");
+        assert_eq!(numbers_to_words("2024-01-15"), "2024-01-15");
+        assert_eq!(numbers_to_words("agent 007"), "agent 007");
+        assert_eq!(numbers_to_words("1, 2"), " one ,  two ");
+    }
+
+    #[test]
+    fn test_numbers_to_words_option() {
+        assert_eq!(
+            slugify!("2 fast 2 furious", numbers_to_words = true),
+            "two-fast-two-furious"
+        );
+        assert_eq!(
+            slugify!("the 101 dalmatians", numbers_to_words = true),
+            "the-one-hundred-one-dalmatians"
+        );
+        assert_eq!(
+            slugify!("top-10 tips (2024)", numbers_to_words = true),
+            "top-ten-tips-2024"
+        );
+        assert_eq!(
+            slugify!("1,000 users", numbers_to_words = true),
+            "1-000-users"
+        );
+        assert_eq!(
+            slugify!("pi is 3.14", numbers_to_words = true),
+            "pi-is-3-14"
+        );
+        assert_eq!(
+            slugify!("2024-01-15 release", numbers_to_words = true),
+            "2024-01-15-release"
+        );
+        assert_eq!(slugify!("agent 007", numbers_to_words = true), "agent-007");
+        assert_eq!(
+            slugify!("chapter 0", numbers_to_words = true),
+            "chapter-zero"
+        );
+        assert_eq!(slugify!("2 fast 2 furious"), "2-fast-2-furious");
+    }
+}
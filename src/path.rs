@@ -0,0 +1,86 @@
+use std::ffi::OsStr;
+
+use crate::{slugify_with_config, SlugConfig};
+
+/// Builds a URL path from discrete parts, slugifying each one with `config` and joining them
+/// with `/`.
+///
+/// Parts that produce an empty slug are left out, so the path never contains `//`.
+///
+/// ```rust
+/// # use slugify_rs::{slugify_from_path_components, SlugConfig};
+/// let config = SlugConfig::default();
+/// assert_eq!(
+///     slugify_from_path_components(&["Blog", "My First Post", "2024"], &config),
+///     "blog/my-first-post/2024"
+/// );
+/// ```
+pub fn slugify_from_path_components(components: &[&str], config: &SlugConfig) -> String {
+    components
+        .iter()
+        .map(|component| slugify_with_config(component, config))
+        .filter(|slug| !slug.is_empty())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Slugifies an `OsStr`, like a file name returned by [`std::fs::DirEntry::file_name`], with
+/// `config`.
+///
+/// The conversion is lossy: invalid UTF-8 sequences, which file names on Linux may contain, are
+/// replaced with `U+FFFD` before slugifying and do not show up in the slug.
+///
+/// ```rust
+/// # use slugify_rs::{slugify_os_str, SlugConfig};
+/// use std::ffi::OsString;
+///
+/// let name = OsString::from("My Holiday Photo.jpg");
+/// assert_eq!(slugify_os_str(&name, &SlugConfig::default()), "my-holiday-photo-jpg");
+/// ```
+pub fn slugify_os_str(s: &OsStr, config: &SlugConfig) -> String {
+    slugify_with_config(s.to_string_lossy(), config)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{slugify_from_path_components, slugify_os_str, SlugConfig};
+
+    #[test]
+    fn test_path_components() {
+        let config = SlugConfig {
+            separator: String::from("_"),
+            ..SlugConfig::default()
+        };
+        assert_eq!(
+            slugify_from_path_components(&["Blog", "My First Post", "2024"], &config),
+            "blog/my_first_post/2024"
+        );
+        assert_eq!(
+            slugify_from_path_components(&["Docs", "???", "A/B Testing"], &config),
+            "docs/a_b_testing"
+        );
+        assert_eq!(slugify_from_path_components(&[], &config), "");
+    }
+
+    #[test]
+    fn test_os_str() {
+        use std::ffi::OsStr;
+
+        let config = SlugConfig::default();
+        assert_eq!(
+            slugify_os_str(OsStr::new("Résumé 2024.pdf"), &config),
+            "resume-2024-pdf"
+        );
+        assert_eq!(slugify_os_str(OsStr::new(""), &config), "");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_os_str_invalid_utf8() {
+        use std::ffi::OsStr;
+        use std::os::unix::ffi::OsStrExt;
+
+        let name = OsStr::from_bytes(b"caf\xe9 menu");
+        assert_eq!(slugify_os_str(name, &SlugConfig::default()), "caf-menu");
+    }
+}
@@ -0,0 +1,280 @@
+use crate::{slugify_audited, SlugConfig};
+
+/// A change made to the input while generating a slug, see [`slugify_redact`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Redaction {
+    /// A stop word was found and removed.
+    StopWordRemoved(String),
+    /// A character was dropped, or replaced by the separator.
+    CharStripped(char),
+    /// `max_length` cut the slug from `from` to `to` bytes.
+    Truncated { from: usize, to: usize },
+    /// A character was replaced by its ASCII transliteration or its override.
+    Transliterated { from: String, to: String },
+}
+
+/// What happened to the input while generating a slug.
+#[derive(Debug, Default)]
+pub(crate) struct Audit {
+    pub(crate) redactions: Vec<Redaction>,
+    pub(crate) random_component: Option<String>,
+    pub(crate) steps: Vec<(&'static str, String)>,
+}
+
+impl Audit {
+    /// Records the string after pipeline stage `name`, if `audit` is present.
+    pub(crate) fn step(audit: &mut Option<&mut Audit>, name: &'static str, s: &str) {
+        if let Some(audit) = audit.as_deref_mut() {
+            audit.steps.push((name, s.to_owned()));
+        }
+    }
+
+    /// Records the redaction built by `redaction`, if `audit` is present.
+    pub(crate) fn record(audit: &mut Option<&mut Audit>, redaction: impl FnOnce() -> Redaction) {
+        if let Some(audit) = audit.as_deref_mut() {
+            audit.redactions.push(redaction());
+        }
+    }
+}
+
+/// Like [`slugify_with_config`](crate::slugify_with_config), but also lists what was removed or
+/// replaced in `s` to get to the slug, in the order it happened.
+///
+/// ```rust
+/// # use slugify_rs::{slugify_redact, Redaction, SlugConfig};
+/// let config = SlugConfig { stop_words: vec![String::from("the")], ..SlugConfig::default() };
+/// let (slug, redactions) = slugify_redact("The café!", &config);
+/// assert_eq!(slug, "cafe");
+/// assert_eq!(
+///     redactions,
+///     [
+///         Redaction::Transliterated { from: String::from("é"), to: String::from("e") },
+///         Redaction::StopWordRemoved(String::from("the")),
+///         Redaction::CharStripped('!'),
+///     ]
+/// );
+/// ```
+pub fn slugify_redact(s: &str, config: &SlugConfig) -> (String, Vec<Redaction>) {
+    let mut audit = Audit::default();
+    let slug = slugify_audited(s, config, Some(&mut audit));
+    (slug, audit.redactions)
+}
+
+/// A summary of how a slug was derived from its text, see [`slugify_with_metadata`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SlugMetadata {
+    /// The text the slug was generated from.
+    pub original: String,
+    /// The stop words found in the text and removed.
+    pub stop_words_removed: Vec<String>,
+    /// Whether any character was replaced by an ASCII transliteration.
+    pub unicode_transliterated: bool,
+    /// Whether `max_length` shortened the slug.
+    pub truncated: bool,
+    /// The random component added to the slug, if any.
+    pub randomness_added: Option<String>,
+}
+
+/// Like [`slugify_with_config`](crate::slugify_with_config), but also returns a summary of how
+/// the slug was derived from `s`. See [`slugify_redact`] for the full list of changes.
+///
+/// ```rust
+/// # use slugify_rs::{slugify_with_metadata, SlugConfig};
+/// let config = SlugConfig { stop_words: vec![String::from("the")], ..SlugConfig::default() };
+/// let (slug, metadata) = slugify_with_metadata("The Café", &config);
+/// assert_eq!(slug, "cafe");
+/// assert_eq!(metadata.original, "The Café");
+/// assert_eq!(metadata.stop_words_removed, ["the"]);
+/// assert!(metadata.unicode_transliterated);
+/// assert!(!metadata.truncated);
+/// assert_eq!(metadata.randomness_added, None);
+/// ```
+pub fn slugify_with_metadata(s: &str, config: &SlugConfig) -> (String, SlugMetadata) {
+    let mut audit = Audit::default();
+    let slug = slugify_audited(s, config, Some(&mut audit));
+
+    let mut metadata = SlugMetadata {
+        original: s.to_owned(),
+        stop_words_removed: Vec::new(),
+        unicode_transliterated: false,
+        truncated: false,
+        randomness_added: audit.random_component,
+    };
+    for redaction in audit.redactions {
+        match redaction {
+            Redaction::StopWordRemoved(word) => metadata.stop_words_removed.push(word),
+            Redaction::Transliterated { .. } => metadata.unicode_transliterated = true,
+            Redaction::Truncated { .. } => metadata.truncated = true,
+            Redaction::CharStripped(_) => {}
+        }
+    }
+    (slug, metadata)
+}
+
+/// The string after each stage of the slug pipeline, see [`slugify_dry_run`].
+///
+/// The stages are:
+///
+/// * `input`: the text, cut to `input_max_length`.
+/// * `deunicode`: transliterated to ASCII, after the transliteration overrides.
+/// * `stop_words`: lowercased, with the stop words and spaces replaced by the separator.
+/// * `main_loop`: with every character that is not kept replaced by a single separator.
+/// * `truncation`: after `strip_leading_digits`, `max_length` and `min_length`.
+/// * `output`: the final slug, with the fallback, random component and URL encoding applied.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SlugTrace {
+    /// The stage names and the string after each of them, in pipeline order.
+    pub steps: Vec<(&'static str, String)>,
+}
+
+/// Generates the slug of `s` with `config` and returns the string after every pipeline stage,
+/// to see where an unexpected slug comes from.
+///
+/// ```rust
+/// # use slugify_rs::{slugify_dry_run, SlugConfig};
+/// let config = SlugConfig { stop_words: vec![String::from("the")], ..SlugConfig::default() };
+/// let trace = slugify_dry_run("the Héllo world!", &config);
+/// assert_eq!(
+///     trace.steps,
+///     [
+///         ("input", String::from("the Héllo world!")),
+///         ("deunicode", String::from("the Hello world!")),
+///         ("stop_words", String::from("--hello-world!")),
+///         ("main_loop", String::from("hello-world")),
+///         ("truncation", String::from("hello-world")),
+///         ("output", String::from("hello-world")),
+///     ]
+/// );
+/// ```
+pub fn slugify_dry_run(s: &str, config: &SlugConfig) -> SlugTrace {
+    let mut audit = Audit::default();
+    slugify_audited(s, config, Some(&mut audit));
+    SlugTrace { steps: audit.steps }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        slugify_dry_run, slugify_redact, slugify_with_config, slugify_with_metadata, Redaction,
+        SlugConfig, TruncationStrategy,
+    };
+
+    #[test]
+    fn test_redact_nothing() {
+        let (slug, redactions) = slugify_redact("hello world", &SlugConfig::default());
+        assert_eq!(slug, "hello-world");
+        assert!(redactions.is_empty());
+    }
+
+    #[test]
+    fn test_redact_chars() {
+        let (slug, redactions) = slugify_redact("Hello, \u{200F}Компьютер", &SlugConfig::default());
+        assert_eq!(slug, "hello-kompiuter");
+        assert_eq!(redactions[0], Redaction::CharStripped('\u{200F}'));
+        assert!(redactions.contains(&Redaction::CharStripped('ь')));
+        assert!(redactions.contains(&Redaction::Transliterated {
+            from: String::from("К"),
+            to: String::from("K"),
+        }));
+        assert_eq!(redactions.last(), Some(&Redaction::CharStripped(',')));
+    }
+
+    #[test]
+    fn test_redact_truncation() {
+        let config = SlugConfig {
+            max_length: Some(8),
+            ..SlugConfig::default()
+        };
+        let (slug, redactions) = slugify_redact("hello world", &config);
+        assert_eq!(slug, "hello-wo");
+        assert_eq!(redactions, [Redaction::Truncated { from: 11, to: 8 }]);
+
+        let config = SlugConfig {
+            max_length: Some(20),
+            truncation: TruncationStrategy::SentenceBoundary,
+            ..SlugConfig::default()
+        };
+        let (slug, redactions) = slugify_redact("First sentence. Second one.", &config);
+        assert_eq!(slug, "first-sentence");
+        assert_eq!(
+            redactions,
+            [
+                Redaction::CharStripped('.'),
+                Redaction::Truncated { from: 25, to: 14 }
+            ]
+        );
+    }
+
+    #[test]
+    fn test_redact_matches_slugify() {
+        let config = SlugConfig {
+            stop_words: vec![String::from("a")],
+            max_length: Some(10),
+            strip_leading_digits: true,
+            ..SlugConfig::default()
+        };
+        for input in ["", "42 A Café", "影師嗎", "---", "a b c d e f g h i j k"].iter() {
+            assert_eq!(
+                slugify_redact(input, &config).0,
+                slugify_with_config(input, &config)
+            );
+        }
+    }
+
+    #[test]
+    fn test_metadata() {
+        let (slug, metadata) = slugify_with_metadata("Hello World", &SlugConfig::default());
+        assert_eq!(slug, "hello-world");
+        assert!(metadata.stop_words_removed.is_empty());
+        assert!(!metadata.unicode_transliterated);
+        assert!(!metadata.truncated);
+        assert_eq!(metadata.randomness_added, None);
+
+        let config = SlugConfig {
+            max_length: Some(5),
+            randomness: true,
+            randomness_prefix: true,
+            ..SlugConfig::default()
+        };
+        let (slug, metadata) = slugify_with_metadata("Hello World", &config);
+        assert!(metadata.truncated);
+        let random = metadata.randomness_added.unwrap();
+        assert_eq!(random.len(), 5);
+        assert_eq!(slug, format!("{}-hello", random));
+    }
+
+    #[test]
+    fn test_dry_run() {
+        let config = SlugConfig {
+            max_length: Some(5),
+            empty_fallback: Some(String::from("untitled")),
+            ..SlugConfig::default()
+        };
+        let names: Vec<&str> = slugify_dry_run("影師", &config)
+            .steps
+            .iter()
+            .map(|(name, _)| *name)
+            .collect();
+        assert_eq!(
+            names,
+            [
+                "input",
+                "deunicode",
+                "stop_words",
+                "main_loop",
+                "truncation",
+                "output"
+            ]
+        );
+
+        let trace = slugify_dry_run("影師 and more", &config);
+        assert_eq!(trace.steps[1].1, "Ying Shi and more");
+        assert_eq!(trace.steps[3].1, "ying-shi-and-more");
+        assert_eq!(trace.steps[4].1, "ying");
+        assert_eq!(trace.steps[5].1, "ying");
+
+        let trace = slugify_dry_run("???", &config);
+        assert_eq!(trace.steps[4].1, "");
+        assert_eq!(trace.steps[5].1, "untitled");
+    }
+}
@@ -0,0 +1,79 @@
+use crate::{slugify_with_config, SlugConfig};
+
+/// Types that know which of their fields a slug is generated from.
+///
+/// ```rust
+/// # use slugify_rs::{slugify_from, Sluggable, SlugConfig};
+/// struct Article {
+///     title: String,
+/// }
+///
+/// impl Sluggable for Article {
+///     fn slug_source(&self) -> &str {
+///         &self.title
+///     }
+///
+///     fn slug_config(&self) -> SlugConfig {
+///         SlugConfig {
+///             stop_words: vec![String::from("the"), String::from("a")],
+///             ..SlugConfig::default()
+///         }
+///     }
+/// }
+///
+/// let article = Article { title: String::from("The Rust Book") };
+/// assert_eq!(slugify_from(&article), "rust-book");
+/// ```
+pub trait Sluggable {
+    /// The text the slug is generated from.
+    fn slug_source(&self) -> &str;
+
+    /// The options used to generate the slug.
+    fn slug_config(&self) -> SlugConfig {
+        SlugConfig::default()
+    }
+}
+
+impl Sluggable for str {
+    fn slug_source(&self) -> &str {
+        self
+    }
+}
+
+/// Generates a slug from the source text of `value`, with its own config.
+pub fn slugify_from<T: Sluggable + ?Sized>(value: &T) -> String {
+    slugify_with_config(value.slug_source(), &value.slug_config())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{slugify_from, SlugConfig, Sluggable};
+
+    struct Product {
+        name: &'static str,
+    }
+
+    impl Sluggable for Product {
+        fn slug_source(&self) -> &str {
+            self.name
+        }
+
+        fn slug_config(&self) -> SlugConfig {
+            SlugConfig {
+                separator: String::from("_"),
+                ..SlugConfig::default()
+            }
+        }
+    }
+
+    #[test]
+    fn test_slugify_from() {
+        assert_eq!(slugify_from("Hello World"), "hello-world");
+        assert_eq!(
+            slugify_from(&Product {
+                name: "Blue Widget"
+            }),
+            "blue_widget"
+        );
+    }
+}
@@ -0,0 +1,126 @@
+use std::fmt;
+
+/// Statistics about a generated slug, see [`slug_stats`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SlugStats {
+    /// Number of words between separators.
+    pub word_count: usize,
+    /// Number of characters, separators included.
+    pub char_count: usize,
+    /// Number of bytes, separators included.
+    pub byte_count: usize,
+    /// Whether the slug is non-empty and every word is made of `a-z` and `0-9` only.
+    pub is_valid: bool,
+    /// Length in characters of the longest word.
+    pub longest_word: usize,
+    /// Length in characters of the shortest word.
+    pub shortest_word: usize,
+}
+
+impl fmt::Display for SlugStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} words, {} chars, {} bytes, longest word {}, shortest word {} ({})",
+            self.word_count,
+            self.char_count,
+            self.byte_count,
+            self.longest_word,
+            self.shortest_word,
+            if self.is_valid { "valid" } else { "invalid" }
+        )
+    }
+}
+
+/// Computes word, character and byte counts of `slug`, whose words are joined with `sep`.
+///
+/// ```rust
+/// # use slugify_rs::slug_stats;
+/// let stats = slug_stats("hello-big-world", "-");
+/// assert_eq!(stats.word_count, 3);
+/// assert_eq!(stats.longest_word, 5);
+/// assert_eq!(stats.shortest_word, 3);
+/// assert!(stats.is_valid);
+/// ```
+pub fn slug_stats(slug: &str, sep: &str) -> SlugStats {
+    let segments: Vec<&str> = if slug.is_empty() {
+        Vec::new()
+    } else if sep.is_empty() {
+        vec![slug]
+    } else {
+        slug.split(sep).collect()
+    };
+    // repeated, leading or trailing separators leave empty segments, which are not words
+    let words: Vec<&str> = segments
+        .iter()
+        .copied()
+        .filter(|word| !word.is_empty())
+        .collect();
+
+    let word_lengths = words.iter().map(|word| word.chars().count());
+
+    SlugStats {
+        word_count: words.len(),
+        char_count: slug.chars().count(),
+        byte_count: slug.len(),
+        is_valid: !words.is_empty()
+            && words.len() == segments.len()
+            && words.iter().all(|word| {
+                word.chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+            }),
+        longest_word: word_lengths.clone().max().unwrap_or(0),
+        shortest_word: word_lengths.min().unwrap_or(0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::slug_stats;
+
+    #[test]
+    fn test_slug_stats() {
+        let stats = slug_stats("the-quick-brown-fox", "-");
+        assert_eq!(stats.word_count, 4);
+        assert_eq!(stats.char_count, 19);
+        assert_eq!(stats.byte_count, 19);
+        assert_eq!(stats.longest_word, 5);
+        assert_eq!(stats.shortest_word, 3);
+        assert!(stats.is_valid);
+        assert_eq!(
+            stats.to_string(),
+            "4 words, 19 chars, 19 bytes, longest word 5, shortest word 3 (valid)"
+        );
+    }
+
+    #[test]
+    fn test_slug_stats_invalid() {
+        assert!(!slug_stats("", "-").is_valid);
+        assert_eq!(slug_stats("", "-").word_count, 0);
+        assert!(!slug_stats("hello--world", "-").is_valid);
+        assert!(!slug_stats("Hello-World", "-").is_valid);
+        assert!(!slug_stats("hello-world-", "-").is_valid);
+    }
+
+    #[test]
+    fn test_slug_stats_empty_segments() {
+        let stats = slug_stats("hello--world", "-");
+        assert_eq!(stats.word_count, 2);
+        assert_eq!(stats.shortest_word, 5);
+        assert!(!stats.is_valid);
+
+        let stats = slug_stats("-hello-", "-");
+        assert_eq!(stats.word_count, 1);
+        assert_eq!(stats.shortest_word, 5);
+        assert_eq!(slug_stats("---", "-").word_count, 0);
+    }
+
+    #[test]
+    fn test_slug_stats_multibyte_separator() {
+        let stats = slug_stats("hello→world", "→");
+        assert_eq!(stats.word_count, 2);
+        assert_eq!(stats.char_count, 11);
+        assert_eq!(stats.byte_count, 13);
+        assert!(stats.is_valid);
+    }
+}
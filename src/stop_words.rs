@@ -0,0 +1,117 @@
+/// Values accepted as stop words by the `slugify!` macro: either a comma separated string or a
+/// list of words.
+///
+/// ```rust
+/// # use slugify_rs::slugify;
+/// assert_eq!(slugify!("the quick fox", stop_words = "the,fox"), "quick");
+/// assert_eq!(slugify!("the quick fox", stop_words = ["the", "fox"]), "quick");
+///
+/// let dynamic = vec![String::from("the"), String::from("fox")];
+/// assert_eq!(slugify!("the quick fox", stop_words = dynamic), "quick");
+/// ```
+pub trait IntoStopWords {
+    /// Converts the value into a list of lowercase stop words, leaving out empty ones.
+    ///
+    /// Stop words are matched against the lowercased input, so `"The"` removes `"the"`.
+    fn into_stop_words(self) -> Vec<String>;
+}
+
+fn split(stop_words: &str) -> Vec<String> {
+    collect(stop_words.split(','))
+}
+
+fn collect<S: AsRef<str>>(words: impl IntoIterator<Item = S>) -> Vec<String> {
+    words
+        .into_iter()
+        .filter(|word| !word.as_ref().is_empty())
+        .map(|word| word.as_ref().to_lowercase())
+        .collect()
+}
+
+impl IntoStopWords for &str {
+    fn into_stop_words(self) -> Vec<String> {
+        split(self)
+    }
+}
+
+impl IntoStopWords for String {
+    fn into_stop_words(self) -> Vec<String> {
+        split(&self)
+    }
+}
+
+impl IntoStopWords for &String {
+    fn into_stop_words(self) -> Vec<String> {
+        split(self)
+    }
+}
+
+impl<S: AsRef<str>, const N: usize> IntoStopWords for [S; N] {
+    fn into_stop_words(self) -> Vec<String> {
+        collect(self)
+    }
+}
+
+impl<S: AsRef<str>> IntoStopWords for &[S] {
+    fn into_stop_words(self) -> Vec<String> {
+        collect(self)
+    }
+}
+
+impl<S: AsRef<str>> IntoStopWords for Vec<S> {
+    fn into_stop_words(self) -> Vec<String> {
+        collect(self)
+    }
+}
+
+impl<S: AsRef<str>> IntoStopWords for &Vec<S> {
+    fn into_stop_words(self) -> Vec<String> {
+        collect(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{slugify, IntoStopWords};
+
+    #[test]
+    fn test_into_stop_words() {
+        assert_eq!("the,,a".into_stop_words(), ["the", "a"]);
+        assert_eq!(String::from("the,a").into_stop_words(), ["the", "a"]);
+        assert_eq!(["the", "", "a"].into_stop_words(), ["the", "a"]);
+        assert_eq!(vec!["the", "a"].into_stop_words(), ["the", "a"]);
+        let words: &[&str] = &["the", "a"];
+        assert_eq!(words.into_stop_words(), ["the", "a"]);
+    }
+
+    #[test]
+    fn test_stop_words_array() {
+        assert_eq!(
+            slugify!("the quick brown fox", stop_words = ["the", "fox"]),
+            "quick-brown"
+        );
+        let words = vec![String::from("quick")];
+        assert_eq!(
+            slugify!("the quick brown fox", stop_words = &words),
+            "the-brown-fox"
+        );
+        assert_eq!(
+            slugify!("the quick brown fox", stop_words = words),
+            "the-brown-fox"
+        );
+    }
+
+    #[test]
+    fn test_stop_words_case_insensitive() {
+        assert_eq!("THE,A".into_stop_words(), ["the", "a"]);
+        assert_eq!(["The", "FoX"].into_stop_words(), ["the", "fox"]);
+        assert_eq!(
+            slugify!("The quick brown fox", stop_words = "THE,FOX"),
+            slugify!("The quick brown fox", stop_words = "the,fox")
+        );
+        assert_eq!(
+            slugify("The Quick Fox", "Quick", "-", None, false, 5),
+            "the-fox"
+        );
+    }
+}
@@ -0,0 +1,611 @@
+//! Slug helpers tailored to the naming rules of specific systems.
+
+use std::collections::HashSet;
+
+use crate::sql_keywords::{
+    MSSQL_RESERVED_WORDS, MYSQL_RESERVED_WORDS, POSTGRES_RESERVED_WORDS, SQLITE_RESERVED_WORDS,
+};
+use crate::{slugify, slugify_truncate_at_separator, slugify_with_config, SlugConfig, SlugError};
+
+/// The database engine a SQL identifier is generated for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SqlEngine {
+    Postgres,
+    Mysql,
+    Sqlite,
+    Mssql,
+}
+
+impl SqlEngine {
+    /// Maximum identifier length accepted by the engine, if any.
+    fn max_identifier_length(self) -> Option<usize> {
+        match self {
+            SqlEngine::Postgres => Some(63),
+            SqlEngine::Mysql => Some(64),
+            SqlEngine::Sqlite => None,
+            SqlEngine::Mssql => Some(128),
+        }
+    }
+
+    /// Whether `word`, in lowercase, is a reserved word of the engine that cannot be used as an
+    /// unquoted identifier.
+    fn is_reserved(self, word: &str) -> bool {
+        let reserved_words: &[&str] = match self {
+            SqlEngine::Postgres => POSTGRES_RESERVED_WORDS,
+            SqlEngine::Mysql => MYSQL_RESERVED_WORDS,
+            SqlEngine::Sqlite => SQLITE_RESERVED_WORDS,
+            SqlEngine::Mssql => MSSQL_RESERVED_WORDS,
+        };
+        reserved_words.contains(&word)
+    }
+}
+
+/// Generates a table or column name from `s`.
+///
+/// Words are joined with `_`, identifiers starting with a digit or matching a reserved word of
+/// `engine`, from the full list in the engine's documentation, are prefixed with `t_` and the
+/// result is cut to the identifier length limit of `engine`.
+///
+/// ```rust
+/// # use slugify_rs::{slugify_sql_identifier, SqlEngine};
+/// assert_eq!(slugify_sql_identifier("First Name", SqlEngine::Postgres).unwrap(), "first_name");
+/// assert_eq!(slugify_sql_identifier("2024 sales", SqlEngine::Mysql).unwrap(), "t_2024_sales");
+/// assert_eq!(slugify_sql_identifier("Order", SqlEngine::Sqlite).unwrap(), "t_order");
+/// ```
+pub fn slugify_sql_identifier(s: &str, engine: SqlEngine) -> Result<String, SlugError> {
+    let mut ident = slugify(s, "", "_", None, false, 5);
+    if ident.is_empty() {
+        return Err(SlugError::EmptyResult);
+    }
+
+    if ident.starts_with(|c: char| c.is_ascii_digit()) || engine.is_reserved(&ident) {
+        ident.insert_str(0, "t_");
+    }
+
+    if let Some(max) = engine.max_identifier_length() {
+        ident.truncate(max);
+        ident.truncate(ident.trim_end_matches('_').len());
+    }
+    Ok(ident)
+}
+
+/// Generates a Kubernetes resource or label name (RFC 1123 label) from `s`.
+///
+/// The result matches `[a-z0-9]([-a-z0-9]*[a-z0-9])?` and is at most 63 characters long, cut at
+/// a word boundary where possible. Names starting with a digit are prefixed with `k`.
+///
+/// ```rust
+/// # use slugify_rs::slugify_for_kubernetes;
+/// assert_eq!(slugify_for_kubernetes("My Web App").unwrap(), "my-web-app");
+/// assert_eq!(slugify_for_kubernetes("3 Replicas").unwrap(), "k3-replicas");
+/// ```
+pub fn slugify_for_kubernetes(s: &str) -> Result<String, SlugError> {
+    let mut name = slugify(s, "", "-", None, false, 5);
+    if name.is_empty() {
+        return Err(SlugError::EmptyResult);
+    }
+
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        name.insert(0, 'k');
+    }
+
+    Ok(slugify_truncate_at_separator(&name, 63, "-"))
+}
+
+/// Generates a Docker image tag from `s`.
+///
+/// Words are joined with `_`, while `.` and `-` are kept so version numbers survive. Tags that
+/// would start with `.` or `-` are prefixed with `v` and the result is at most 128 characters.
+///
+/// ```rust
+/// # use slugify_rs::slugify_for_docker_tag;
+/// assert_eq!(slugify_for_docker_tag("My App 1.2.3-beta"), "my_app_1.2.3-beta");
+/// assert_eq!(slugify_for_docker_tag(".1.0"), "v.1.0");
+/// ```
+pub fn slugify_for_docker_tag(s: &str) -> String {
+    let config = SlugConfig {
+        separator: String::from("_"),
+        allow_chars: vec!['.', '-'],
+        ..SlugConfig::default()
+    };
+    let mut tag = slugify_with_config(s, &config);
+
+    if tag.starts_with(|c| c == '.' || c == '-') {
+        tag.insert(0, 'v');
+    }
+
+    tag.truncate(128);
+    tag.truncate(tag.trim_end_matches('_').len());
+    tag
+}
+
+/// Generates an npm package name from `name`.
+///
+/// Words are joined with `-`. Fails with [`SlugError::InvalidResult`] if the name is longer than
+/// the 214 characters npm accepts, or if the name or its slug is one npm does not allow for
+/// unscoped packages: `node_modules`, `favicon.ico` and the Node.js core modules like `http` or
+/// `fs`.
+///
+/// ```rust
+/// # use slugify_rs::{slugify_for_npm_package, SlugError};
+/// assert_eq!(slugify_for_npm_package("My Cool Lib").unwrap(), "my-cool-lib");
+/// assert!(matches!(slugify_for_npm_package("HTTP"), Err(SlugError::InvalidResult(_))));
+/// ```
+pub fn slugify_for_npm_package(name: &str) -> Result<String, SlugError> {
+    let slug = slugify(name, "", "-", None, false, 5);
+    if slug.is_empty() {
+        return Err(SlugError::EmptyResult);
+    }
+    validate_npm_name(&slug)?;
+    let lowercase = name.trim().to_lowercase();
+    for candidate in [lowercase.as_str(), slug.as_str()].iter() {
+        if NPM_BLACKLIST.contains(candidate) {
+            return Err(SlugError::InvalidResult(format!(
+                "`{}` is not allowed as an npm package name",
+                candidate
+            )));
+        }
+        if NODE_CORE_MODULES.contains(candidate) {
+            return Err(SlugError::InvalidResult(format!(
+                "`{}` is the name of a Node.js core module",
+                candidate
+            )));
+        }
+    }
+    Ok(slug)
+}
+
+/// Generates a scoped npm package name, `@scope/name`, from `scope` and `name`.
+///
+/// Both parts are slugified like [`slugify_for_npm_package`] and the full name must fit in 214
+/// characters. Core module names like `http` are fine behind a scope.
+///
+/// ```rust
+/// # use slugify_rs::slugify_for_npm_scoped;
+/// assert_eq!(slugify_for_npm_scoped("Acme Corp", "UI Kit").unwrap(), "@acme-corp/ui-kit");
+/// ```
+pub fn slugify_for_npm_scoped(scope: &str, name: &str) -> Result<String, SlugError> {
+    let scope = slugify(scope, "", "-", None, false, 5);
+    let name = slugify(name, "", "-", None, false, 5);
+    if scope.is_empty() || name.is_empty() {
+        return Err(SlugError::EmptyResult);
+    }
+    validate_npm_name(&scope)?;
+    validate_npm_name(&name)?;
+
+    let full = format!("@{}/{}", scope, name);
+    if full.len() > 214 {
+        return Err(SlugError::InvalidResult(String::from(
+            "npm package names are limited to 214 characters",
+        )));
+    }
+    Ok(full)
+}
+
+/// Names npm never accepts for a package.
+const NPM_BLACKLIST: &[&str] = &["node_modules", "favicon.ico"];
+
+/// Modules built into Node.js, which npm does not accept as unscoped package names, as listed by
+/// `require('module').builtinModules` in Node.js 20 without the internal `_` ones.
+const NODE_CORE_MODULES: &[&str] = &[
+    "assert",
+    "assert/strict",
+    "async_hooks",
+    "buffer",
+    "child_process",
+    "cluster",
+    "console",
+    "constants",
+    "crypto",
+    "dgram",
+    "diagnostics_channel",
+    "dns",
+    "dns/promises",
+    "domain",
+    "events",
+    "fs",
+    "fs/promises",
+    "http",
+    "http2",
+    "https",
+    "inspector",
+    "inspector/promises",
+    "module",
+    "net",
+    "os",
+    "path",
+    "path/posix",
+    "path/win32",
+    "perf_hooks",
+    "process",
+    "punycode",
+    "querystring",
+    "readline",
+    "readline/promises",
+    "repl",
+    "stream",
+    "stream/consumers",
+    "stream/promises",
+    "stream/web",
+    "string_decoder",
+    "sys",
+    "timers",
+    "timers/promises",
+    "tls",
+    "trace_events",
+    "tty",
+    "url",
+    "util",
+    "util/types",
+    "v8",
+    "vm",
+    "wasi",
+    "worker_threads",
+    "zlib",
+];
+
+/// Checks a single npm name segment against the length limit. The segments are slugs joined with
+/// `-`, so they are always lowercase, URL-safe and never start with `.` or `_`.
+fn validate_npm_name(name: &str) -> Result<(), SlugError> {
+    if name.len() > 214 {
+        return Err(SlugError::InvalidResult(String::from(
+            "npm package names are limited to 214 characters",
+        )));
+    }
+    Ok(())
+}
+
+/// Generates a GitHub repository name from `s`.
+///
+/// Words are joined with `-`, `.` and `_` are kept and the name is cut to 100 characters at a
+/// word boundary. The special `.github` repository name passes through unchanged, while the
+/// reserved names `.` and `..` get a `-repo` suffix.
+///
+/// ```rust
+/// # use slugify_rs::slugify_for_github_repo;
+/// assert_eq!(slugify_for_github_repo("My Dotfiles"), "my-dotfiles");
+/// assert_eq!(slugify_for_github_repo("spa5k/slugify_rs"), "spa5k-slugify_rs");
+/// assert_eq!(slugify_for_github_repo(".github"), ".github");
+/// ```
+pub fn slugify_for_github_repo(s: &str) -> String {
+    let config = SlugConfig {
+        allow_chars: vec!['.', '_'],
+        ..SlugConfig::default()
+    };
+    let mut name = slugify_with_config(s, &config);
+
+    if name == "." || name == ".." {
+        name.push_str("-repo");
+    } else if name != ".github" {
+        name = slugify_truncate_at_separator(&name, 100, "-");
+    }
+    name
+}
+
+/// Words left out of column names by [`slugify_from_csv_header`].
+const CSV_HEADER_STOP_WORDS: &[&str] = &["a", "an", "the", "of"];
+
+/// Generates a database column name from a CSV header.
+///
+/// Words are joined with `_` and the words `a`, `an`, `the` and `of` are left out, unless the
+/// header has no other words.
+///
+/// ```rust
+/// # use slugify_rs::slugify_from_csv_header;
+/// assert_eq!(slugify_from_csv_header("Email Address"), "email_address");
+/// assert_eq!(slugify_from_csv_header("Date Of Birth"), "date_birth");
+/// ```
+pub fn slugify_from_csv_header(header: &str) -> String {
+    let column = slugify(header, "", "_", None, false, 5);
+    let words: Vec<&str> = column
+        .split('_')
+        .filter(|word| !CSV_HEADER_STOP_WORDS.contains(word))
+        .collect();
+    if words.is_empty() {
+        column
+    } else {
+        words.join("_")
+    }
+}
+
+/// Generates database column names from a CSV header row, like [`slugify_from_csv_header`].
+///
+/// Names already taken by an earlier header get a `_2`, `_3`, ... suffix, and headers that
+/// produce an empty name are called `column`.
+///
+/// ```rust
+/// # use slugify_rs::slugify_from_csv_headers;
+/// assert_eq!(
+///     slugify_from_csv_headers(&["Name", "E-mail", "name", ""]),
+///     ["name", "e_mail", "name_2", "column"]
+/// );
+/// ```
+pub fn slugify_from_csv_headers(headers: &[&str]) -> Vec<String> {
+    let mut taken = HashSet::new();
+    headers
+        .iter()
+        .map(|header| {
+            let mut column = slugify_from_csv_header(header);
+            if column.is_empty() {
+                column.push_str("column");
+            }
+            let mut unique = column.clone();
+            let mut n = 1;
+            while !taken.insert(unique.clone()) {
+                n += 1;
+                unique = format!("{}_{}", column, n);
+            }
+            unique
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        slugify_for_docker_tag, slugify_for_github_repo, slugify_for_kubernetes,
+        slugify_for_npm_package, slugify_for_npm_scoped, slugify_from_csv_header,
+        slugify_from_csv_headers, slugify_sql_identifier, SlugError, SqlEngine,
+    };
+
+    #[test]
+    fn test_sql_identifier() {
+        assert_eq!(
+            slugify_sql_identifier("Email Address", SqlEngine::Sqlite).unwrap(),
+            "email_address"
+        );
+        assert_eq!(
+            slugify_sql_identifier("1st place", SqlEngine::Postgres).unwrap(),
+            "t_1st_place"
+        );
+        assert_eq!(
+            slugify_sql_identifier("???", SqlEngine::Postgres),
+            Err(SlugError::EmptyResult)
+        );
+    }
+
+    #[test]
+    fn test_sql_identifier_reserved_words() {
+        for engine in [
+            SqlEngine::Postgres,
+            SqlEngine::Mysql,
+            SqlEngine::Sqlite,
+            SqlEngine::Mssql,
+        ] {
+            assert_eq!(
+                slugify_sql_identifier("Select", engine).unwrap(),
+                "t_select"
+            );
+            assert_eq!(
+                slugify_sql_identifier(" ORDER ", engine).unwrap(),
+                "t_order"
+            );
+            assert_eq!(
+                slugify_sql_identifier("Order Date", engine).unwrap(),
+                "order_date"
+            );
+            assert_eq!(slugify_sql_identifier("orders", engine).unwrap(), "orders");
+        }
+        // words only reserved by some engines
+        assert_eq!(
+            slugify_sql_identifier("User", SqlEngine::Postgres).unwrap(),
+            "t_user"
+        );
+        assert_eq!(
+            slugify_sql_identifier("User", SqlEngine::Mysql).unwrap(),
+            "user"
+        );
+        assert_eq!(
+            slugify_sql_identifier("Top", SqlEngine::Mssql).unwrap(),
+            "t_top"
+        );
+        assert_eq!(
+            slugify_sql_identifier("Top", SqlEngine::Postgres).unwrap(),
+            "top"
+        );
+        // words from the full lists of each engine
+        let reserved = [
+            (SqlEngine::Postgres, "lateral"),
+            (SqlEngine::Postgres, "Current Schema"),
+            (SqlEngine::Mysql, "rank"),
+            (SqlEngine::Mysql, "bigint"),
+            (SqlEngine::Sqlite, "abort"),
+            (SqlEngine::Sqlite, "View"),
+            (SqlEngine::Mssql, "pivot"),
+            (SqlEngine::Mssql, "merge"),
+        ];
+        for (engine, word) in reserved.iter() {
+            let ident = slugify_sql_identifier(word, *engine).unwrap();
+            assert!(ident.starts_with("t_"), "{:?} {:?}", engine, ident);
+        }
+        // key is not reserved in PostgreSQL
+        assert_eq!(
+            slugify_sql_identifier("key", SqlEngine::Postgres).unwrap(),
+            "key"
+        );
+        assert_eq!(
+            slugify_sql_identifier("key", SqlEngine::Mysql).unwrap(),
+            "t_key"
+        );
+    }
+
+    #[test]
+    fn test_sql_identifier_length() {
+        let long = "word ".repeat(40);
+        assert_eq!(
+            slugify_sql_identifier(&long, SqlEngine::Postgres)
+                .unwrap()
+                .len(),
+            63
+        );
+        assert_eq!(
+            slugify_sql_identifier(&long, SqlEngine::Mssql)
+                .unwrap()
+                .len(),
+            128
+        );
+        assert_eq!(
+            slugify_sql_identifier(&long, SqlEngine::Sqlite)
+                .unwrap()
+                .len(),
+            199
+        );
+    }
+
+    #[test]
+    fn test_kubernetes() {
+        assert_eq!(
+            slugify_for_kubernetes("  Payment Service (v2)  ").unwrap(),
+            "payment-service-v2"
+        );
+        assert_eq!(slugify_for_kubernetes("__a__b__").unwrap(), "a-b");
+        assert_eq!(slugify_for_kubernetes("2048").unwrap(), "k2048");
+        assert_eq!(slugify_for_kubernetes("!!!"), Err(SlugError::EmptyResult));
+    }
+
+    #[test]
+    fn test_kubernetes_length() {
+        let name = slugify_for_kubernetes(&"service ".repeat(20)).unwrap();
+        assert_eq!(name.len(), 63);
+        assert!(name.ends_with("service"));
+
+        let name = slugify_for_kubernetes(&"backend ".repeat(20)).unwrap();
+        assert!(name.len() <= 63);
+        assert!(name.ends_with("backend"));
+
+        let name = slugify_for_kubernetes(&"x".repeat(100)).unwrap();
+        assert_eq!(name.len(), 63);
+    }
+
+    #[test]
+    fn test_docker_tag() {
+        assert_eq!(slugify_for_docker_tag("v1.2.3"), "v1.2.3");
+        assert_eq!(
+            slugify_for_docker_tag("Release 2.0.0-rc.1"),
+            "release_2.0.0-rc.1"
+        );
+        assert_eq!(
+            slugify_for_docker_tag("Ünïcode Build #42"),
+            "unicode_build_42"
+        );
+        assert_eq!(slugify_for_docker_tag("-nightly"), "v-nightly");
+        assert_eq!(slugify_for_docker_tag(&"1.0 ".repeat(50)).len(), 127);
+    }
+
+    #[test]
+    fn test_npm_package() {
+        assert_eq!(
+            slugify_for_npm_package("_Private Utils_").unwrap(),
+            "private-utils"
+        );
+        assert_eq!(slugify_for_npm_package(".dotfile").unwrap(), "dotfile");
+        for name in [
+            "node_modules",
+            "Favicon.ico",
+            "http",
+            " FS ",
+            "events",
+            "child_process",
+        ]
+        .iter()
+        {
+            assert!(
+                matches!(
+                    slugify_for_npm_package(name),
+                    Err(SlugError::InvalidResult(_))
+                ),
+                "{}",
+                name
+            );
+        }
+        assert_eq!(
+            slugify_for_npm_package("http client").unwrap(),
+            "http-client"
+        );
+        assert_eq!(slugify_for_npm_package("@@@"), Err(SlugError::EmptyResult));
+        assert!(matches!(
+            slugify_for_npm_package(&"word ".repeat(50)),
+            Err(SlugError::InvalidResult(_))
+        ));
+    }
+
+    #[test]
+    fn test_npm_scoped() {
+        assert_eq!(
+            slugify_for_npm_scoped("@My Org", "Data Grid").unwrap(),
+            "@my-org/data-grid"
+        );
+        assert_eq!(
+            slugify_for_npm_scoped("acme", "http").unwrap(),
+            "@acme/http"
+        );
+        assert_eq!(
+            slugify_for_npm_scoped("", "grid"),
+            Err(SlugError::EmptyResult)
+        );
+        assert!(matches!(
+            slugify_for_npm_scoped(&"a".repeat(100), &"b".repeat(114)),
+            Err(SlugError::InvalidResult(_))
+        ));
+        assert_eq!(
+            slugify_for_npm_scoped(&"a".repeat(100), &"b".repeat(112))
+                .unwrap()
+                .len(),
+            214
+        );
+    }
+
+    #[test]
+    fn test_github_repo() {
+        assert_eq!(slugify_for_github_repo("Hello World"), "hello-world");
+        assert_eq!(slugify_for_github_repo("my.site_v2"), "my.site_v2");
+        assert_eq!(slugify_for_github_repo(".GitHub"), ".github");
+        assert_eq!(
+            slugify_for_github_repo(".github/workflows"),
+            ".github-workflows"
+        );
+        assert_eq!(slugify_for_github_repo("."), ".-repo");
+        assert_eq!(slugify_for_github_repo(" .. "), "..-repo");
+    }
+
+    #[test]
+    fn test_github_repo_slashes() {
+        assert_eq!(slugify_for_github_repo("owner/repo"), "owner-repo");
+        assert_eq!(
+            slugify_for_github_repo("https://github.com/spa5k/slugify-rs"),
+            "https-github.com-spa5k-slugify-rs"
+        );
+        assert_eq!(slugify_for_github_repo("/leading/slash/"), "leading-slash");
+    }
+
+    #[test]
+    fn test_github_repo_length() {
+        let name = slugify_for_github_repo(&"project ".repeat(30));
+        assert!(name.len() <= 100);
+        assert!(name.ends_with("project"));
+    }
+
+    #[test]
+    fn test_csv_header() {
+        assert_eq!(slugify_from_csv_header("First Name"), "first_name");
+        assert_eq!(slugify_from_csv_header("  Date of Birth "), "date_birth");
+        assert_eq!(
+            slugify_from_csv_header("The Number Of Items"),
+            "number_items"
+        );
+        // stop words only count as whole words
+        assert_eq!(slugify_from_csv_header("Profile Theme"), "profile_theme");
+        assert_eq!(slugify_from_csv_header("The"), "the");
+        assert_eq!(slugify_from_csv_header("Prénom"), "prenom");
+        assert_eq!(slugify_from_csv_header(""), "");
+    }
+
+    #[test]
+    fn test_csv_headers() {
+        assert_eq!(
+            slugify_from_csv_headers(&["ID", "Name", "name", "NAME", "Name 2", "", "???"]),
+            ["id", "name", "name_2", "name_3", "name_2_2", "column", "column_2"]
+        );
+        assert!(slugify_from_csv_headers(&[]).is_empty());
+    }
+}
@@ -0,0 +1,95 @@
+/// How a slug longer than `max_length` is shortened.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TruncationStrategy {
+    /// Cut at `max_length` and remove the separators left at the end. This is the default.
+    ExactChar,
+    /// Cut before the last separator within `max_length`, so no word is cut in half. A first
+    /// word longer than `max_length` is cut like `ExactChar`.
+    WordBoundary,
+    /// Keep as many whole sentences of the input (ending with `.`, `!` or `?` followed by
+    /// whitespace) as fit in `max_length`, falling back to `WordBoundary`.
+    SentenceBoundary,
+    /// Cut at `max_length` exactly, even if the slug then ends with a separator.
+    NoBoundary,
+}
+
+impl Default for TruncationStrategy {
+    fn default() -> Self {
+        TruncationStrategy::ExactChar
+    }
+}
+
+/// Cuts `s` to at most `max_len` bytes, ending it before the last `sep` that fits so no word is
+/// cut in half.
+///
+/// Strings without a separator in reach are cut at the last character boundary that fits.
+/// Separators left at the end are removed.
+///
+/// ```rust
+/// # use slugify_rs::slugify_truncate_at_separator;
+/// assert_eq!(slugify_truncate_at_separator("hello-world-foo", 8, "-"), "hello");
+/// assert_eq!(slugify_truncate_at_separator("hello-world-foo", 11, "-"), "hello-world");
+/// assert_eq!(slugify_truncate_at_separator("helloworld", 5, "-"), "hello");
+/// ```
+pub fn slugify_truncate_at_separator(s: &str, max_len: usize, sep: &str) -> String {
+    if s.len() <= max_len {
+        return s.to_owned();
+    }
+
+    let cut = if sep.is_empty() {
+        None
+    } else {
+        s.match_indices(sep)
+            .map(|(i, _)| i)
+            .take_while(|&i| i <= max_len)
+            .filter(|&i| i > 0)
+            .last()
+    };
+    let cut = cut.unwrap_or_else(|| {
+        let mut cut = max_len;
+        while !s.is_char_boundary(cut) {
+            cut -= 1;
+        }
+        cut
+    });
+
+    let mut truncated = &s[..cut];
+    if !sep.is_empty() {
+        truncated = truncated.trim_end_matches(sep);
+    }
+    truncated.to_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::slugify_truncate_at_separator;
+
+    #[test]
+    fn test_truncate_at_separator() {
+        assert_eq!(
+            slugify_truncate_at_separator("hello-world", 20, "-"),
+            "hello-world"
+        );
+        assert_eq!(
+            slugify_truncate_at_separator("hello-world", 5, "-"),
+            "hello"
+        );
+        assert_eq!(slugify_truncate_at_separator("hello-world", 4, "-"), "hell");
+        assert_eq!(slugify_truncate_at_separator("a--b--c", 5, "-"), "a--b");
+        assert_eq!(slugify_truncate_at_separator("-hello", 3, "-"), "-he");
+        assert_eq!(
+            slugify_truncate_at_separator("hello world", 8, ""),
+            "hello wo"
+        );
+        assert_eq!(slugify_truncate_at_separator("hello", 0, "-"), "");
+    }
+
+    #[test]
+    fn test_truncate_at_multibyte_separator() {
+        assert_eq!(slugify_truncate_at_separator("ab→cd→ef", 9, "→"), "ab→cd");
+        assert_eq!(slugify_truncate_at_separator("ab→cd→ef", 8, "→"), "ab→cd");
+        assert_eq!(slugify_truncate_at_separator("ab→cd→ef", 7, "→"), "ab→cd");
+        assert_eq!(slugify_truncate_at_separator("ab→cd→ef", 6, "→"), "ab");
+        assert_eq!(slugify_truncate_at_separator("ééé", 3, "-"), "é");
+    }
+}
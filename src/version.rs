@@ -0,0 +1,77 @@
+use crate::{slugify_with_config, SlugConfig};
+
+/// Combines a product name and a version into a slug, `"My App"` and `"1.2.3"` giving
+/// `"my-app-1-2-3"`.
+///
+/// The version is expected to be ASCII already: it is not transliterated, only lowercased, with
+/// every run of characters other than `a-z` and `0-9` replaced with a single `-`.
+///
+/// ```rust
+/// # use slugify_rs::slugify_version;
+/// assert_eq!(slugify_version("My App", "1.2.3"), "my-app-1-2-3");
+/// assert_eq!(slugify_version("My App", "1.0 RC"), "my-app-1-0-rc");
+/// ```
+pub fn slugify_version(name: &str, version: &str) -> String {
+    slugify_version_with_separator(name, version, "-")
+}
+
+/// Like [`slugify_version`], but joins the name and the version with `version_sep`.
+///
+/// ```rust
+/// # use slugify_rs::slugify_version_with_separator;
+/// assert_eq!(slugify_version_with_separator("My App", "1.2.3", "/"), "my-app/1-2-3");
+/// ```
+pub fn slugify_version_with_separator(name: &str, version: &str, version_sep: &str) -> String {
+    let name = slugify_with_config(name, &SlugConfig::default());
+    let version = version_slug(version);
+
+    match (name.is_empty(), version.is_empty()) {
+        (_, true) => name,
+        (true, false) => version,
+        (false, false) => format!("{}{}{}", name, version_sep, version),
+    }
+}
+
+/// Lowercases `version` and replaces every run of characters other than `a-z` and `0-9` with a
+/// single `-`.
+fn version_slug(version: &str) -> String {
+    let mut slug = String::with_capacity(version.len());
+    for c in version.chars() {
+        let c = c.to_ascii_lowercase();
+        if c.is_ascii_lowercase() || c.is_ascii_digit() {
+            slug.push(c);
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    slug.truncate(slug.trim_end_matches('-').len());
+    slug
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{slugify_version, slugify_version_with_separator};
+
+    #[test]
+    fn test_slugify_version() {
+        assert_eq!(slugify_version("My App", "1.2.3"), "my-app-1-2-3");
+        assert_eq!(slugify_version("Ünïcode Tool", "2.0"), "unicode-tool-2-0");
+        assert_eq!(slugify_version("My App", "1.0.0-beta"), "my-app-1-0-0-beta");
+        assert_eq!(slugify_version("My App", ""), "my-app");
+        assert_eq!(slugify_version("", "1.2"), "1-2");
+        assert_eq!(slugify_version("My App", "1.0 RC"), "my-app-1-0-rc");
+        assert_eq!(
+            slugify_version("My App", " V2.0 -- Final! "),
+            "my-app-v2-0-final"
+        );
+        assert_eq!(slugify_version("My App", "..."), "my-app");
+    }
+
+    #[test]
+    fn test_slugify_version_with_separator() {
+        assert_eq!(
+            slugify_version_with_separator("My App", "1.2.3", "/"),
+            "my-app/1-2-3"
+        );
+    }
+}
@@ -0,0 +1,182 @@
+use std::io::{self, Write};
+use std::str;
+
+use crate::{slugify_with_config, SlugConfig};
+
+/// An [`io::Write`] adapter that slugifies the lines written to it, like [`slugify_lines`], and
+/// writes each slug followed by `\n` to an inner writer.
+///
+/// Only the line being written is kept in memory, so large streams like log files can be
+/// slugified as they are read. Bytes may be written in chunks of any size, even splitting a
+/// UTF-8 sequence, as a line is only decoded once it is complete. Blank lines, and lines that
+/// produce an empty slug, are skipped.
+///
+/// The last line is only written once the writer is [finished](SlugWriter::finish), unless it
+/// ends with `\n`.
+///
+/// [`slugify_lines`]: crate::slugify_lines
+///
+/// ```rust
+/// # use slugify_rs::{SlugConfig, SlugWriter};
+/// use std::io::Write;
+///
+/// let mut writer = SlugWriter::new(Vec::new(), SlugConfig::default());
+/// writer.write_all("Hello World\nCafé ".as_bytes())?;
+/// writer.write_all("Crème\n\nLast Line".as_bytes())?;
+/// let out = writer.finish()?;
+/// assert_eq!(String::from_utf8(out).unwrap(), "hello-world\ncafe-creme\nlast-line\n");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Debug)]
+pub struct SlugWriter<W: Write> {
+    inner: W,
+    config: SlugConfig,
+    line: Vec<u8>,
+}
+
+impl<W: Write> SlugWriter<W> {
+    /// Creates a writer slugifying lines with `config` and writing the slugs to `inner`.
+    pub fn new(inner: W, config: SlugConfig) -> Self {
+        SlugWriter {
+            inner,
+            config,
+            line: Vec::new(),
+        }
+    }
+
+    /// Returns a reference to the inner writer.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Writes the slug of the last line, if it did not end with `\n`, flushes the inner writer and
+    /// returns it.
+    pub fn finish(mut self) -> io::Result<W> {
+        let line = std::mem::take(&mut self.line);
+        self.write_slug(&line)?;
+        self.inner.flush()?;
+        Ok(self.inner)
+    }
+
+    fn write_slug(&mut self, line: &[u8]) -> io::Result<()> {
+        let line = str::from_utf8(line)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?
+            .trim();
+        if line.is_empty() {
+            return Ok(());
+        }
+        let mut slug = slugify_with_config(line, &self.config);
+        if slug.is_empty() {
+            return Ok(());
+        }
+        slug.push('\n');
+        self.inner.write_all(slug.as_bytes())
+    }
+}
+
+impl<W: Write> Write for SlugWriter<W> {
+    /// Slugifies the lines completed by `buf`.
+    ///
+    /// If a line fails, like one that is not valid UTF-8, the lines before it are reported as
+    /// written and the error is returned by the next call, which then consumes nothing.
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut consumed = 0;
+        while let Some(end) = buf[consumed..].iter().position(|&b| b == b'\n') {
+            let rest = &buf[consumed..consumed + end];
+            let result = if self.line.is_empty() {
+                self.write_slug(rest)
+            } else {
+                let mut line = self.line.clone();
+                line.extend_from_slice(rest);
+                self.write_slug(&line)
+            };
+            match result {
+                Ok(()) => {
+                    self.line.clear();
+                    consumed += end + 1;
+                }
+                Err(_) if consumed > 0 => return Ok(consumed),
+                Err(err) => return Err(err),
+            }
+        }
+        self.line.extend_from_slice(&buf[consumed..]);
+        Ok(buf.len())
+    }
+
+    /// Flushes the inner writer. A line that is not finished yet is kept back, as more of it may
+    /// still be written.
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use crate::{slugify_lines, SlugConfig, SlugWriter};
+
+    fn write_in_chunks(text: &str, chunk: usize, config: SlugConfig) -> String {
+        let mut writer = SlugWriter::new(Vec::new(), config);
+        for bytes in text.as_bytes().chunks(chunk) {
+            writer.write_all(bytes).unwrap();
+        }
+        String::from_utf8(writer.finish().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_slug_writer() {
+        let text = "  First Title  \n\n!!!\r\nZürich Straße\r\n影師嗎\nLast";
+        let expected = "first-title\nzurich-strasse\nying-shi-ma\nlast\n";
+        // most chunk sizes split some of the multi-byte characters
+        for chunk in 1..=text.len() {
+            assert_eq!(
+                write_in_chunks(text, chunk, SlugConfig::default()),
+                expected
+            );
+        }
+
+        let config = SlugConfig {
+            separator: String::from("_"),
+            ..SlugConfig::default()
+        };
+        let lines = slugify_lines(text, &config);
+        assert_eq!(write_in_chunks(text, 3, config), lines.join("\n") + "\n");
+        assert_eq!(write_in_chunks("", 1, SlugConfig::default()), "");
+    }
+
+    #[test]
+    fn test_slug_writer_invalid_utf8() {
+        let mut writer = SlugWriter::new(Vec::new(), SlugConfig::default());
+        writer.write_all(b"fine\n").unwrap();
+        let err = writer.write_all(b"caf\xe9\n").unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        assert_eq!(writer.get_ref().as_slice(), b"fine\n");
+    }
+
+    #[test]
+    fn test_slug_writer_invalid_line_in_buffer() {
+        let mut writer = SlugWriter::new(Vec::new(), SlugConfig::default());
+        let buf = b"ok\n\xff\nmore\n";
+        // only the line before the invalid one is consumed
+        assert_eq!(writer.write(buf).unwrap(), 3);
+        assert_eq!(writer.get_ref().as_slice(), b"ok\n");
+
+        // the error comes with the next call, which consumes nothing
+        let err = writer.write(&buf[3..]).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        assert_eq!(writer.get_ref().as_slice(), b"ok\n");
+
+        // write_all stops at the invalid line too
+        let mut writer = SlugWriter::new(Vec::new(), SlugConfig::default());
+        assert!(writer.write_all(buf).is_err());
+        assert_eq!(writer.get_ref().as_slice(), b"ok\n");
+
+        // a pending partial line is kept when the line it starts turns out to be invalid
+        let mut writer = SlugWriter::new(Vec::new(), SlugConfig::default());
+        assert_eq!(writer.write(b"caf").unwrap(), 3);
+        assert!(writer.write(b"\xe9\n").is_err());
+        assert_eq!(writer.write(b"e\n").unwrap(), 2);
+        assert_eq!(writer.finish().unwrap(), b"cafe\n");
+    }
+}
@@ -0,0 +1,24 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+#[test]
+fn reports_colliding_lines() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_slugify-collisions"))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(b"Hello World\nUnique\nhello, world!\n\n???\nHELLO WORLD\n")
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "\"hello-world\": lines 1, 3, 6\n\"\": lines 4, 5\n2 colliding slugs across 6 lines\n"
+    );
+}
@@ -0,0 +1,60 @@
+//! Invariants that must hold for any input, checked with proptest against arbitrary strings.
+
+use proptest::prelude::*;
+use slugify_rs::{slugify, slugify_with_config, SlugConfig};
+
+/// Arbitrary text mixed with words and separators, so stop words and runs of separators show up
+/// more often than in `any::<String>()` alone.
+fn wordy_string() -> impl Strategy<Value = String> {
+    (
+        any::<String>(),
+        "((the|fox|The|a|7|é|影| |-|_|\\.|,)){0,12}",
+    )
+        .prop_map(|(noise, words)| format!("{}{}", words, noise))
+}
+
+proptest! {
+    #[test]
+    fn output_is_ascii(s in any::<String>()) {
+        let slug = slugify(&s, "", "-", None, false, 5);
+        prop_assert!(slug.is_ascii(), "{:?} -> {:?}", s, slug);
+    }
+
+    #[test]
+    fn output_respects_max_length(s in any::<String>(), max_length in 0usize..24) {
+        let slug = slugify(&s, "", "-", Some(max_length), false, 5);
+        prop_assert!(slug.len() <= max_length, "{:?} -> {:?}", s, slug);
+    }
+
+    #[test]
+    fn output_has_no_consecutive_separators(s in wordy_string()) {
+        for sep in ["-", "_", "."].iter() {
+            let slug = slugify(&s, "", sep, None, false, 5);
+            prop_assert!(!slug.contains(&sep.repeat(2)), "{:?} -> {:?}", s, slug);
+            prop_assert!(!slug.starts_with(sep), "{:?} -> {:?}", s, slug);
+            prop_assert!(!slug.ends_with(sep), "{:?} -> {:?}", s, slug);
+        }
+    }
+
+    #[test]
+    fn slugify_is_idempotent(s in any::<String>()) {
+        let slug = slugify(&s, "", "-", None, false, 5);
+        let again = slugify(&slug, "", "-", None, false, 5);
+        prop_assert_eq!(&slug, &again, "{:?}", s);
+    }
+
+    #[test]
+    fn stop_words_never_appear_as_words(s in wordy_string()) {
+        let config = SlugConfig {
+            stop_words: vec![String::from("the"), String::from("fox")],
+            ..SlugConfig::default()
+        };
+        let slug = slugify_with_config(&s, &config);
+        prop_assert!(
+            slug.split('-').all(|word| word != "the" && word != "fox"),
+            "{:?} -> {:?}",
+            s,
+            slug
+        );
+    }
+}